@@ -1,30 +1,103 @@
-use criterion::{criterion_group, criterion_main, Criterion};
-
-macro_rules! benchmark {
-    ($name: ident) => {
-        fn $name(c: &mut Criterion) {
-            let input = std::fs::read_to_string(format!(
-                "{}/inputs/{}",
-                env!("CARGO_MANIFEST_DIR"),
-                stringify!($name)
-            ))
-            .unwrap();
-            c.bench_function(stringify!($name), |b| {
-                b.iter(|| aoc23::$name::solve(&input, false, false))
+use aoc23::answers::Answers;
+use aoc23::solution::{Answer, DynSolution, Params, ParsedInput};
+use aoc23::{Year, YEARS};
+use criterion::measurement::WallTime;
+use criterion::{criterion_group, criterion_main, BenchmarkGroup, Criterion};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+// Example inputs are benchmarked only when this variable is set.
+const EXAMPLES_VAR: &str = "AOC_BENCH_EXAMPLES";
+
+// Phases slower than this take the minimal number of samples, so that days
+// running for seconds finish in reasonable time.
+const SLOW: Duration = Duration::from_millis(200);
+
+type Part = fn(&dyn DynSolution, &ParsedInput) -> anyhow::Result<Answer>;
+
+const PARTS: [(&str, Part); 2] = [("part1", |s, i| s.part1(i)), ("part2", |s, i| s.part2(i))];
+
+fn inputs_dir(year: &Year) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("inputs")
+        .join(year.year.to_string())
+}
+
+fn bench<O>(group: &mut BenchmarkGroup<WallTime>, name: &str, mut f: impl FnMut() -> O) {
+    let start = Instant::now();
+    f();
+    group.sample_size(if start.elapsed() > SLOW { 10 } else { 100 });
+    group.bench_function(name, |b| b.iter(&mut f));
+}
+
+fn parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+    for year in YEARS {
+        for (day, solution) in year.days.iter().enumerate() {
+            let input =
+                std::fs::read_to_string(inputs_dir(year).join(format!("day{:02}", day + 1)))
+                    .unwrap();
+            let name = format!("{}/day{:02}", year.year, day + 1);
+            bench(&mut group, &name, || {
+                solution.parse(&input, &Params::default()).unwrap()
             });
         }
-    };
+    }
+    group.finish();
 }
 
-macro_rules! benchmarks {
-    ($($name:ident),+) => {
-        $(
-            benchmark!{$name}
-        )+
+fn parts(c: &mut Criterion) {
+    for (part, run) in PARTS {
+        let mut group = c.benchmark_group(part);
+        for year in YEARS {
+            for (day, solution) in year.days.iter().enumerate() {
+                let path = inputs_dir(year).join(format!("day{:02}", day + 1));
+                let input = solution
+                    .parse(&std::fs::read_to_string(path).unwrap(), &Params::default())
+                    .unwrap();
+                let name = format!("{}/day{:02}", year.year, day + 1);
+                bench(&mut group, &name, || run(*solution, &input).unwrap());
+            }
+        }
+        group.finish();
+    }
+}
 
-        criterion_group!(benches, $($name,)+);
-        criterion_main!(benches);
+// Parts of examples that have expected answers, since the others may not
+// terminate on an example.
+fn examples(c: &mut Criterion) {
+    if std::env::var_os(EXAMPLES_VAR).is_none() {
+        return;
+    }
+    let mut group = c.benchmark_group("examples");
+    for year in YEARS {
+        let dir = inputs_dir(year);
+        let answers = Answers::load(&dir.join("answers")).unwrap();
+        let mut names: Vec<String> = std::fs::read_dir(&dir)
+            .unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+            .filter(|name| name.get(5..).is_some_and(|s| s.starts_with("_test")))
+            .collect();
+        names.sort();
+        for name in names {
+            let day: usize = name[3..5].parse().unwrap();
+            let solution = year.days[day - 1];
+            let input = solution
+                .parse(
+                    &std::fs::read_to_string(dir.join(&name)).unwrap(),
+                    &answers.params(&name),
+                )
+                .unwrap();
+            for (i, (part, run)) in PARTS.iter().enumerate() {
+                if answers.is_checked(&name, i + 1) {
+                    let id = format!("{}/{name}/{part}", year.year);
+                    bench(&mut group, &id, || run(solution, &input).unwrap());
+                }
+            }
+        }
     }
+    group.finish();
 }
 
-benchmarks! {day01,day02,day03,day04,day05,day06,day07,day08,day09,day10,day11,day12,day13,day14,day15,day16,day17,day18,day19,day20/*,day21,day22,day23,day24,day25*/}
+criterion_group!(benches, parse, parts, examples);
+criterion_main!(benches);
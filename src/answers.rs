@@ -0,0 +1,213 @@
+use anyhow::{bail, Context, Result};
+use rustc_hash::FxHashMap;
+use std::fmt::Display;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::solution::{Answer, Params};
+
+const SKIPPED: &str = "-";
+
+/// Expected answers keyed by input file name.
+///
+/// Each non empty line holds the file name followed by the answers for
+/// consecutive parts, separated by whitespace. A `-` in place of an answer
+/// means that part is not checked. Answers may be followed by `key=value`
+/// parameters of the input, see [`Params`]. Lines starting with `#` are
+/// comments.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    expected: FxHashMap<String, Vec<String>>,
+    params: FxHashMap<String, Params>,
+}
+
+impl FromStr for Answers {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut answers = Self::default();
+        for (line_no, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut tokens = line.split_whitespace();
+            let name = tokens.next().unwrap().to_owned();
+            let (params, expected): (Vec<&str>, Vec<&str>) =
+                tokens.partition(|token| token.contains('='));
+            if expected.is_empty() {
+                bail!("line {}: no answers for '{name}'", line_no + 1);
+            }
+            let expected = expected.into_iter().map(|s| s.to_owned()).collect();
+            if answers.expected.insert(name.clone(), expected).is_some() {
+                bail!("line {}: duplicated answers for '{name}'", line_no + 1);
+            }
+            let params = params
+                .into_iter()
+                .map(|p| {
+                    let (key, value) = p.split_once('=').unwrap();
+                    (key.to_owned(), value.to_owned())
+                })
+                .collect();
+            answers.params.insert(name, params);
+        }
+        Ok(answers)
+    }
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self> {
+        std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read answers: {}", path.display()))?
+            .parse()
+            .with_context(|| format!("Failed to parse answers: {}", path.display()))
+    }
+
+    pub fn get(&self, input_name: &str) -> Option<&[String]> {
+        self.expected.get(input_name).map(|v| v.as_slice())
+    }
+
+    /// Returns parameters of `input_name`, which are empty when not given.
+    pub fn params(&self, input_name: &str) -> Params {
+        self.params.get(input_name).cloned().unwrap_or_default()
+    }
+
+    /// Returns whether `part` (counted from 1) of `input_name` has an expected answer.
+    pub fn is_checked(&self, input_name: &str, part: usize) -> bool {
+        self.get(input_name)
+            .and_then(|expected| expected.get(part - 1))
+            .is_some_and(|expected| expected != SKIPPED)
+    }
+
+    /// Sets the expected answer of `part` (counted from 1) of `input_name`,
+    /// unless it already has one.
+    pub fn confirm(&mut self, input_name: &str, part: usize, answer: &str) {
+        let expected = self.expected.entry(input_name.to_owned()).or_default();
+        if expected.len() < part {
+            expected.resize(part, SKIPPED.to_owned());
+        }
+        if expected[part - 1] == SKIPPED {
+            expected[part - 1] = answer.to_owned();
+        }
+    }
+
+    /// Compares answers computed for `input_name` with the expected ones.
+    pub fn check(&self, input_name: &str, actual: &[Answer]) -> Check {
+        let Some(expected) = self.get(input_name) else {
+            return Check::Missing;
+        };
+        let mismatches: Vec<Mismatch> = actual
+            .iter()
+            .zip(expected)
+            .enumerate()
+            .filter(|(_, (_, expected))| *expected != SKIPPED)
+            .filter(|(_, (actual, expected))| &actual.to_string() != *expected)
+            .map(|(part, (actual, expected))| Mismatch {
+                part: part + 1,
+                expected: expected.clone(),
+                actual: actual.clone(),
+            })
+            .collect();
+        if mismatches.is_empty() {
+            Check::Ok
+        } else {
+            Check::Failed(mismatches)
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub part: usize,
+    pub expected: String,
+    pub actual: Answer,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "\tpart {}:", self.part)?;
+        writeln!(f, "\t- expected: {}", self.expected)?;
+        write!(f, "\t+ actual:   {}", self.actual)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Ok,
+    /// No expected answers are known for this input.
+    Missing,
+    Failed(Vec<Mismatch>),
+}
+
+impl Check {
+    /// Ignores mismatches of parts other than `part`, which were not run.
+    pub fn only_part(self, part: usize) -> Check {
+        match self {
+            Check::Failed(mut mismatches) => {
+                mismatches.retain(|m| m.part == part);
+                if mismatches.is_empty() {
+                    Check::Ok
+                } else {
+                    Check::Failed(mismatches)
+                }
+            }
+            check => check,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_reports_mismatched_parts() {
+        let answers: Answers = "# comment\nday01 1 2\n\nday25 3\nday08_test3 - 6\n"
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            Check::Ok,
+            answers.check("day01", &[Answer::Num(1), Answer::Num(2)])
+        );
+        assert_eq!(
+            Check::Ok,
+            answers.check("day25", &[Answer::Num(3), Answer::None])
+        );
+        assert_eq!(
+            Check::Ok,
+            answers.check("day08_test3", &[Answer::Num(100), Answer::Num(6)])
+        );
+        assert!(!answers.is_checked("day08_test3", 1));
+        assert!(answers.is_checked("day08_test3", 2));
+        assert_eq!(
+            Check::Missing,
+            answers.check("day02", &[Answer::Num(1), Answer::Num(2)])
+        );
+        assert_eq!(
+            Check::Failed(vec![Mismatch {
+                part: 2,
+                expected: "2".to_owned(),
+                actual: Answer::Num(5),
+            }]),
+            answers.check("day01", &[Answer::Num(1), Answer::Num(5)])
+        );
+    }
+
+    #[test]
+    fn reads_params() {
+        let answers: Answers = "day21_test 16 - steps=6\nday21 1 2".parse().unwrap();
+        assert_eq!(
+            Some(&["16".to_owned(), "-".to_owned()][..]),
+            answers.get("day21_test")
+        );
+        assert_eq!(6, answers.params("day21_test").get("steps", 64).unwrap());
+        assert_eq!(64, answers.params("day21").get("steps", 64).unwrap());
+        assert!("day21_test steps=6".parse::<Answers>().is_err());
+    }
+
+    #[test]
+    fn rejects_duplicates() {
+        assert!("day01 1 2\nday01 1 2".parse::<Answers>().is_err());
+    }
+}
@@ -0,0 +1,146 @@
+use anyhow::{Context, Result};
+use rustc_hash::FxHashMap;
+use std::fmt::Display;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::report::DayReport;
+
+/// Timings of an earlier run, keyed by year and day.
+///
+/// Stored in the same JSON format as `--format json` output, so any saved
+/// report can serve as a baseline.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Baseline(FxHashMap<(u16, usize), DayReport>);
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Self> {
+        let file = std::fs::File::open(path)
+            .with_context(|| format!("Failed to read baseline: {}", path.display()))?;
+        let reports: Vec<DayReport> = serde_json::from_reader(std::io::BufReader::new(file))
+            .with_context(|| format!("Failed to parse baseline: {}", path.display()))?;
+        Ok(Self(
+            reports.into_iter().map(|r| ((r.year, r.day), r)).collect(),
+        ))
+    }
+
+    /// Saves timings of `reports`, leaving out days without usable timings.
+    pub fn save(path: &Path, reports: &[DayReport]) -> Result<()> {
+        let timed: Vec<&DayReport> = reports.iter().filter(|r| r.is_timed()).collect();
+        let file = std::fs::File::create(path)
+            .with_context(|| format!("Failed to create baseline: {}", path.display()))?;
+        serde_json::to_writer_pretty(file, &timed)
+            .with_context(|| format!("Failed to write baseline: {}", path.display()))
+    }
+
+    /// Compares `report` with the baseline of the same year, day and input.
+    /// `threshold` is the allowed slowdown of compute time, in percent.
+    /// Baselines of days that did not finish are ignored.
+    pub fn compare(&self, report: &DayReport, threshold: f64) -> Option<Delta> {
+        let base = self
+            .0
+            .get(&(report.year, report.day))
+            .filter(|b| b.input == report.input && b.is_timed())?;
+        let compute = change(base.compute_ns, report.compute_ns);
+        Some(Delta {
+            base_compute: Duration::from_nanos(base.compute_ns),
+            compute,
+            with_io: change(base.with_io_ns, report.with_io_ns),
+            regression: compute > threshold,
+        })
+    }
+}
+
+// Relative change from `old` to `new`, in percent.
+fn change(old: u64, new: u64) -> f64 {
+    (new as f64 - old as f64) / (old.max(1) as f64) * 100.0
+}
+
+/// Difference between a day and its baseline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Delta {
+    pub base_compute: Duration,
+    /// Change of compute time, in percent.
+    pub compute: f64,
+    /// Change of time with i/o, in percent.
+    pub with_io: f64,
+    /// Compute time grew by more than the threshold.
+    pub regression: bool,
+}
+
+impl Display for Delta {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:+.1}% vs baseline {:.1?} (with i/o: {:+.1}%)",
+            self.compute, self.base_compute, self.with_io
+        )?;
+        if self.regression {
+            write!(f, " REGRESSION")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::Status;
+
+    // Report in the format of the first baselines, which later fields must
+    // keep loading.
+    fn report(day: usize, compute_ns: u64) -> DayReport {
+        serde_json::from_value(serde_json::json!({
+            "day": day,
+            "input": format!("day{day:02}"),
+            "compute_ns": compute_ns,
+            "with_io_ns": compute_ns * 2,
+            "runs": 1,
+            "median_ns": compute_ns,
+            "mean_ns": compute_ns,
+            "stddev_ns": 0,
+            "p95_ns": compute_ns,
+            "part1": null,
+            "part2": null,
+            "status": "ok",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn flags_days_slower_than_threshold() {
+        let baseline = Baseline(
+            [((2023, 1), report(1, 100)), ((2023, 2), report(2, 100))]
+                .into_iter()
+                .collect(),
+        );
+
+        let delta = baseline.compare(&report(1, 125), 10.0).unwrap();
+        assert_eq!(25.0, delta.compute);
+        assert!(delta.regression);
+        assert!(!baseline.compare(&report(2, 105), 10.0).unwrap().regression);
+        assert_eq!(None, baseline.compare(&report(3, 100), 10.0));
+    }
+
+    #[test]
+    fn ignores_unfinished_days() {
+        let unfinished = DayReport::unfinished(
+            2023,
+            1,
+            "day01".to_owned(),
+            Status::TimedOut,
+            "timed out".to_owned(),
+        );
+        let baseline = Baseline([((2023, 1), unfinished.clone())].into_iter().collect());
+        assert_eq!(None, baseline.compare(&report(1, 100), 10.0));
+
+        let path = std::env::temp_dir().join(format!("aoc23-baseline-{}", std::process::id()));
+        Baseline::save(&path, &[unfinished, report(2, 100)]).unwrap();
+        let saved = Baseline::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(
+            Baseline([((2023, 2), report(2, 100))].into_iter().collect()),
+            saved
+        );
+    }
+}
@@ -0,0 +1,328 @@
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+const BASE_URL: &str = "https://adventofcode.com";
+const SESSION_VAR: &str = "AOC_SESSION";
+
+/// HTTP transport used to talk to the Advent of Code site, replaceable in tests.
+pub trait Http {
+    /// Returns the body of a successful response to a GET of `url`.
+    fn get(&self, url: &str, session: &str) -> Result<String>;
+    /// Returns the body of a successful response to a POST of `form` to `url`.
+    fn post_form(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<String>;
+}
+
+/// [`Http`] over a real network connection.
+pub struct UreqHttp(ureq::Agent);
+
+impl Default for UreqHttp {
+    fn default() -> Self {
+        Self(
+            ureq::AgentBuilder::new()
+                .user_agent(concat!(
+                    "aoc23/",
+                    env!("CARGO_PKG_VERSION"),
+                    " (",
+                    env!("CARGO_PKG_AUTHORS"),
+                    ")"
+                ))
+                .build(),
+        )
+    }
+}
+
+impl Http for UreqHttp {
+    fn get(&self, url: &str, session: &str) -> Result<String> {
+        let response = self
+            .0
+            .get(url)
+            .set("Cookie", &format!("session={session}"))
+            .call()
+            .with_context(|| format!("GET {url}"))?;
+        Ok(response.into_string()?)
+    }
+
+    fn post_form(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<String> {
+        let response = self
+            .0
+            .post(url)
+            .set("Cookie", &format!("session={session}"))
+            .send_form(form)
+            .with_context(|| format!("POST {url}"))?;
+        Ok(response.into_string()?)
+    }
+}
+
+/// Session token of a logged in user, from the `AOC_SESSION` environment
+/// variable or the `~/.config/aoc23/session` file.
+pub fn session_token() -> Result<String> {
+    if let Ok(session) = std::env::var(SESSION_VAR) {
+        return Ok(session.trim().to_owned());
+    }
+    let path = std::env::var_os("HOME")
+        .map(|home| Path::new(&home).join(".config/aoc23/session"))
+        .context("HOME is not set")?;
+    let session = std::fs::read_to_string(&path).with_context(|| {
+        format!(
+            "No session token in {SESSION_VAR} nor in {}",
+            path.display()
+        )
+    })?;
+    Ok(session.trim().to_owned())
+}
+
+/// Client of the Advent of Code site for a single user.
+pub struct Client<H = UreqHttp> {
+    http: H,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(session: String) -> Self {
+        Self::with_http(UreqHttp::default(), BASE_URL.to_owned(), session)
+    }
+}
+
+impl<H: Http> Client<H> {
+    pub fn with_http(http: H, base_url: String, session: String) -> Self {
+        Self {
+            http,
+            base_url,
+            session,
+        }
+    }
+
+    pub fn input(&self, year: u16, day: usize) -> Result<String> {
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        self.http.get(&url, &self.session)
+    }
+
+    /// Submits `answer` to `part` of `day` of `year`.
+    pub fn submit(&self, year: u16, day: usize, part: usize, answer: &str) -> Result<Response> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let level = part.to_string();
+        let page = self.http.post_form(
+            &url,
+            &self.session,
+            &[("level", &level), ("answer", answer)],
+        )?;
+        Ok(Response::from_page(&page))
+    }
+}
+
+/// Outcome of submitting an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Rejected without a hint.
+    Wrong,
+    /// Not checked, because the previous answer was submitted too recently.
+    TooRecent,
+    /// Not checked, because the part is already solved or still locked.
+    WrongLevel,
+    /// Not checked, for a reason not recognized.
+    Unknown,
+}
+
+impl Verdict {
+    /// Returns whether the answer was checked and found wrong.
+    pub fn is_rejection(self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+            Verdict::TooRecent => "too recent",
+            Verdict::WrongLevel => "wrong level",
+            Verdict::Unknown => "unknown",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Response to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub verdict: Verdict,
+    /// Text of the response, without markup.
+    pub message: String,
+}
+
+impl Response {
+    fn from_page(page: &str) -> Self {
+        let article = page
+            .split_once("<article>")
+            .and_then(|(_, rest)| rest.split_once("</article>"))
+            .map_or(page, |(article, _)| article);
+        let mut message = String::new();
+        let mut in_tag = false;
+        for c in article.chars() {
+            match c {
+                '<' => in_tag = true,
+                '>' => in_tag = false,
+                c if !in_tag => message.push(c),
+                _ => {}
+            }
+        }
+        let message = message.split_whitespace().collect::<Vec<_>>().join(" ");
+        let verdict = if message.contains("That's the right answer") {
+            Verdict::Correct
+        } else if message.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if message.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if message.contains("That's not the right answer") {
+            Verdict::Wrong
+        } else if message.contains("You gave an answer too recently") {
+            Verdict::TooRecent
+        } else if message.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown
+        };
+        Self { verdict, message }
+    }
+}
+
+/// Path of the cached input of `day` of `year` in `inputs`.
+pub fn input_path(inputs: &Path, year: u16, day: usize) -> PathBuf {
+    inputs.join(year.to_string()).join(format!("day{day:02}"))
+}
+
+/// Whether the input at `path` is downloaded. The empty placeholder created by
+/// `new-day` does not count.
+pub fn is_cached(path: &Path) -> bool {
+    std::fs::metadata(path).is_ok_and(|m| m.len() > 0)
+}
+
+/// Downloads the input of `day` of `year` into `inputs`. Inputs never change,
+/// so an input that is already cached is never fetched again.
+pub fn fetch_input<H: Http>(
+    client: &Client<H>,
+    inputs: &Path,
+    year: u16,
+    day: usize,
+) -> Result<PathBuf> {
+    let path = input_path(inputs, year, day);
+    if is_cached(&path) {
+        bail!(
+            "{} is already cached, not fetching it again",
+            path.display()
+        );
+    }
+    let input = client.input(year, day)?;
+    std::fs::create_dir_all(path.parent().unwrap())?;
+    std::fs::write(&path, input).with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(path)
+}
+
+/// Stub of the Advent of Code site, serving canned pages on a local port.
+#[cfg(test)]
+pub(crate) mod stub {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::JoinHandle;
+
+    /// Request received by the stub: request line, headers and body.
+    pub struct Request {
+        pub line: String,
+        pub headers: Vec<String>,
+        pub body: String,
+    }
+
+    /// Serves a single request with `page`. Returns the base URL of the
+    /// stub, and a handle returning the request it received.
+    pub fn serve_once(page: &'static str) -> (String, JoinHandle<Request>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(&stream);
+            let mut lines = vec![];
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let line = line.trim_end().to_owned();
+                if line.is_empty() {
+                    break;
+                }
+                lines.push(line);
+            }
+            let length = lines
+                .iter()
+                .find_map(|l| l.strip_prefix("Content-Length: "))
+                .map_or(0, |n| n.parse().unwrap());
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{page}",
+                page.len()
+            )
+            .unwrap();
+            let line = lines.remove(0);
+            Request {
+                line,
+                headers: lines,
+                body: String::from_utf8(body).unwrap(),
+            }
+        });
+        (url, server)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::stub::serve_once;
+    use super::*;
+
+    #[test]
+    fn fetches_input_once() {
+        let (url, server) = serve_once("1 2 3\n");
+        let client = Client::with_http(UreqHttp::default(), url, "secret".to_owned());
+        let inputs = std::env::temp_dir().join(format!("aoc23-fetch-{}", std::process::id()));
+
+        let path = fetch_input(&client, &inputs, 2023, 7).unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!("1 2 3\n", std::fs::read_to_string(&path).unwrap());
+        assert_eq!("GET /2023/day/7/input HTTP/1.1", request.line);
+        assert!(request
+            .headers
+            .contains(&"Cookie: session=secret".to_owned()));
+        assert!(fetch_input(&client, &inputs, 2023, 7).is_err());
+        std::fs::remove_dir_all(inputs).unwrap();
+    }
+
+    #[test]
+    fn submits_answer() {
+        let (url, server) = serve_once(
+            "<main><article><p>That's not the right answer; your answer is too \
+             <em>high</em>.</p></article></main>",
+        );
+        let client = Client::with_http(UreqHttp::default(), url, "secret".to_owned());
+
+        let response = client.submit(2023, 7, 2, "42").unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!("POST /2023/day/7/answer HTTP/1.1", request.line);
+        assert_eq!("level=2&answer=42", request.body);
+        assert_eq!(Verdict::TooHigh, response.verdict);
+        assert_eq!(
+            "That's not the right answer; your answer is too high.",
+            response.message
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::cmp::{Ordering, Reverse};
 use std::collections::{BinaryHeap, VecDeque};
 use std::fmt::Debug;
@@ -22,7 +22,8 @@ pub fn path<T: PartialEq + Eq + Hash + Clone>(
     Some(path)
 }
 
-// Returns cost of path from start and previous nodes for path reconstruction.
+// Returns previous nodes for path reconstruction, stopping once a node
+// satisfying `is_target` is reached.
 pub fn bfs<T>(
     start: T,
     is_target: impl Fn(&T) -> bool,
@@ -37,11 +38,11 @@ where
 
     while let Some(next) = todo.pop_front() {
         if is_target(&next) {
-            assert!(next == start || prev.get(&next).is_some());
+            assert!(next == start || prev.contains_key(&next));
             return prev;
         }
         for candidate in neighbours_of(&next) {
-            if prev.get(&candidate).is_some() {
+            if prev.contains_key(&candidate) {
                 continue;
             }
             todo.push_back(candidate.clone());
@@ -52,6 +53,89 @@ where
     prev
 }
 
+/// Iterator over nodes in order of the number of steps needed to reach them
+/// from any of the starts. Each item is the frontier at the next step.
+pub struct BfsLayers<T, F> {
+    seen: FxHashSet<T>,
+    frontier: Vec<T>,
+    neighbours_of: F,
+}
+
+impl<T, V, F> Iterator for BfsLayers<T, F>
+where
+    T: Eq + Hash + Clone,
+    V: IntoIterator<Item = T>,
+    F: Fn(&T) -> V,
+{
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.frontier.is_empty() {
+            return None;
+        }
+        let mut next = vec![];
+        for node in &self.frontier {
+            for neighbour in (self.neighbours_of)(node) {
+                if self.seen.insert(neighbour.clone()) {
+                    next.push(neighbour);
+                }
+            }
+        }
+        Some(std::mem::replace(&mut self.frontier, next))
+    }
+}
+
+pub fn bfs_layers<T, V, F>(start: &[T], neighbours_of: F) -> BfsLayers<T, F>
+where
+    T: Eq + Hash + Clone,
+    V: IntoIterator<Item = T>,
+    F: Fn(&T) -> V,
+{
+    let mut seen: FxHashSet<T> = Default::default();
+    let frontier = start
+        .iter()
+        .filter(|s| seen.insert((*s).clone()))
+        .cloned()
+        .collect();
+    BfsLayers {
+        seen,
+        frontier,
+        neighbours_of,
+    }
+}
+
+// Returns number of steps from the nearest start to every reachable node.
+pub fn bfs_distances<T, V>(start: &[T], neighbours_of: impl Fn(&T) -> V) -> FxHashMap<T, usize>
+where
+    T: Eq + Hash + Clone,
+    V: IntoIterator<Item = T>,
+{
+    bfs_layers(start, neighbours_of)
+        .enumerate()
+        .flat_map(|(dist, layer)| layer.into_iter().map(move |node| (node, dist)))
+        .collect()
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct State<U: Debug + PartialEq + Eq + PartialOrd + Ord, V: Debug + PartialOrd + Ord> {
+    key: U,
+    prio: V,
+}
+
+impl<U: Debug + PartialOrd + Ord, V: Debug + PartialOrd + Ord> Ord for State<U, V> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.prio
+            .cmp(&other.prio)
+            .then_with(|| self.key.cmp(&other.key))
+    }
+}
+
+impl<U: Debug + PartialOrd + Ord, V: Debug + PartialOrd + Ord> PartialOrd for State<U, V> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 // Returns cost of path from start and previous nodes for path reconstruction.
 pub fn dijkstra<T, P, V>(
     start: &[T],
@@ -69,36 +153,286 @@ where
 
     let mut prev: FxHashMap<T, T> = Default::default();
 
-    #[derive(Debug, PartialEq, Eq, Ord)]
-    struct State<U: Debug + PartialEq + Eq + PartialOrd + Ord, V: Debug + PartialOrd + Ord> {
-        key: U,
-        prio: V,
+    let mut todo: BinaryHeap<Reverse<State<T, P>>> = BinaryHeap::default();
+    for s in start {
+        todo.push(Reverse(State {
+            key: s.clone(),
+            prio: dist.get(s).unwrap().clone(),
+        }));
     }
 
-    impl<U: Debug + PartialOrd + Ord, V: Debug + PartialOrd + Ord> PartialOrd for State<U, V> {
-        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-            let o = self.prio.partial_cmp(&other.prio);
-            if o == Some(Ordering::Equal) {
-                self.key.partial_cmp(&other.key)
-            } else {
-                o
+    while let Some(Reverse(State { key, prio })) = todo.pop() {
+        for (neighbour, cost) in neighbours_of(&key) {
+            let alt = prio.clone() + cost;
+            if dist.get(&neighbour).map(|p| &alt < p).unwrap_or(true) {
+                dist.insert(neighbour.clone(), alt.clone());
+                prev.insert(neighbour.clone(), key.clone());
+                todo.push(Reverse(State {
+                    key: neighbour,
+                    prio: alt,
+                }));
             }
         }
     }
+    (dist, prev)
+}
+
+// Returns cost and path from one of the starts to the first goal reached, using
+// `heuristic` to guide the search. The heuristic must never overestimate the
+// remaining cost for the result to be optimal.
+pub fn astar<T, P, V>(
+    start: &[T],
+    is_goal: impl Fn(&T) -> bool,
+    neighbours_of: impl Fn(&T) -> V,
+    heuristic: impl Fn(&T) -> P,
+) -> Option<(P, Vec<T>)>
+where
+    T: Debug + PartialEq + Eq + PartialOrd + Ord + Hash + Clone,
+    P: Debug + PartialEq + Eq + PartialOrd + Ord + Default + Clone + Add<Output = P>,
+    V: IntoIterator<Item = (T, P)>,
+{
+    let mut dist: FxHashMap<T, P> = Default::default();
+    let mut prev: FxHashMap<T, T> = Default::default();
     let mut todo: BinaryHeap<Reverse<State<T, P>>> = BinaryHeap::default();
     for s in start {
+        dist.insert(s.clone(), P::default());
         todo.push(Reverse(State {
             key: s.clone(),
-            prio: dist.get(s).unwrap().clone(),
+            prio: heuristic(s),
         }));
     }
 
     while let Some(Reverse(State { key, prio })) = todo.pop() {
-        for (neighbour, cost) in neighbours_of(&key) {
-            let alt = prio.clone() + cost;
+        let cost = dist.get(&key).unwrap().clone();
+        if cost.clone() + heuristic(&key) < prio {
+            // Reached with a lower cost since it was queued.
+            continue;
+        }
+        if is_goal(&key) {
+            let mut path = vec![key];
+            while let Some(p) = prev.get(path.last().unwrap()) {
+                path.push(p.clone());
+            }
+            path.reverse();
+            return Some((cost, path));
+        }
+        for (neighbour, step) in neighbours_of(&key) {
+            let alt = cost.clone() + step;
             if dist.get(&neighbour).map(|p| &alt < p).unwrap_or(true) {
                 dist.insert(neighbour.clone(), alt.clone());
                 prev.insert(neighbour.clone(), key.clone());
+                let prio = alt + heuristic(&neighbour);
+                todo.push(Reverse(State {
+                    key: neighbour,
+                    prio,
+                }));
+            }
+        }
+    }
+    None
+}
+
+// Returns cost and path from one of the starts to the first target reached.
+pub fn dijkstra_to<T, P, V>(
+    start: &[T],
+    is_target: impl Fn(&T) -> bool,
+    neighbours_of: impl Fn(&T) -> V,
+) -> Option<(P, Vec<T>)>
+where
+    T: Debug + PartialEq + Eq + PartialOrd + Ord + Hash + Clone,
+    P: Debug + PartialEq + Eq + PartialOrd + Ord + Default + Clone + Add<Output = P>,
+    V: IntoIterator<Item = (T, P)>,
+{
+    astar(start, is_target, neighbours_of, |_| P::default())
+}
+
+// Returns cost of the shortest paths from the starts to the nearest targets and
+// all paths of that cost. Costs must be positive, as zero-cost cycles would
+// make the number of paths infinite.
+pub fn dijkstra_all_to<T, P, V>(
+    start: &[T],
+    is_target: impl Fn(&T) -> bool,
+    neighbours_of: impl Fn(&T) -> V,
+) -> Option<(P, Vec<Vec<T>>)>
+where
+    T: Debug + PartialEq + Eq + PartialOrd + Ord + Hash + Clone,
+    P: Debug + PartialEq + Eq + PartialOrd + Ord + Default + Clone + Add<Output = P>,
+    V: IntoIterator<Item = (T, P)>,
+{
+    let mut dist: FxHashMap<T, P> = Default::default();
+    let mut prev: FxHashMap<T, Vec<T>> = Default::default();
+    let mut todo: BinaryHeap<Reverse<State<T, P>>> = BinaryHeap::default();
+    for s in start {
+        dist.insert(s.clone(), P::default());
+        todo.push(Reverse(State {
+            key: s.clone(),
+            prio: P::default(),
+        }));
+    }
+
+    let mut best: Option<P> = None;
+    let mut targets = vec![];
+    while let Some(Reverse(State { key, prio })) = todo.pop() {
+        if best.as_ref().is_some_and(|b| &prio > b) {
+            break;
+        }
+        if dist.get(&key).is_some_and(|d| d < &prio) {
+            continue;
+        }
+        if is_target(&key) {
+            best = Some(prio);
+            targets.push(key);
+            continue;
+        }
+        for (neighbour, step) in neighbours_of(&key) {
+            let alt = prio.clone() + step;
+            match dist.get(&neighbour) {
+                Some(d) if &alt > d => {}
+                Some(d) if &alt == d => prev.get_mut(&neighbour).unwrap().push(key.clone()),
+                _ => {
+                    dist.insert(neighbour.clone(), alt.clone());
+                    prev.insert(neighbour.clone(), vec![key.clone()]);
+                    todo.push(Reverse(State {
+                        key: neighbour,
+                        prio: alt,
+                    }));
+                }
+            }
+        }
+    }
+
+    // Follow all previous nodes back from the targets, building reversed paths.
+    let mut paths = vec![];
+    let mut partial: Vec<Vec<T>> = targets.into_iter().map(|t| vec![t]).collect();
+    while let Some(path) = partial.pop() {
+        match prev.get(path.last().unwrap()) {
+            Some(prevs) => {
+                for p in prevs {
+                    let mut longer = path.clone();
+                    longer.push(p.clone());
+                    partial.push(longer);
+                }
+            }
+            None => {
+                let mut path = path;
+                path.reverse();
+                paths.push(path);
+            }
+        }
+    }
+    best.map(|b| (b, paths))
+}
+
+// Like `bfs_distances`, for nodes with a dense index in `0..len` given by
+// `index_of`. Returns number of steps from the nearest start to every node.
+pub fn bfs_dense<T, V>(
+    start: &[T],
+    len: usize,
+    index_of: impl Fn(&T) -> usize,
+    neighbours_of: impl Fn(&T) -> V,
+) -> Vec<Option<usize>>
+where
+    T: Clone,
+    V: IntoIterator<Item = T>,
+{
+    let mut dist = vec![None; len];
+    let mut todo: VecDeque<(T, usize)> = Default::default();
+    for s in start {
+        let idx = index_of(s);
+        if dist[idx].is_none() {
+            dist[idx] = Some(0);
+            todo.push_back((s.clone(), 0));
+        }
+    }
+    while let Some((node, d)) = todo.pop_front() {
+        for neighbour in neighbours_of(&node) {
+            let idx = index_of(&neighbour);
+            if dist[idx].is_none() {
+                dist[idx] = Some(d + 1);
+                todo.push_back((neighbour, d + 1));
+            }
+        }
+    }
+    dist
+}
+
+// Like `dijkstra`, for nodes with a dense index in `0..len` given by
+// `index_of`. Returns cost of path from start and previous nodes, both indexed
+// by `index_of`.
+pub fn dijkstra_dense<T, P, V>(
+    start: &[T],
+    len: usize,
+    index_of: impl Fn(&T) -> usize,
+    neighbours_of: impl Fn(&T) -> V,
+) -> (Vec<Option<P>>, Vec<Option<T>>)
+where
+    T: Debug + PartialEq + Eq + PartialOrd + Ord + Clone,
+    P: Debug + PartialEq + Eq + PartialOrd + Ord + Default + Clone + Add<Output = P>,
+    V: IntoIterator<Item = (T, P)>,
+{
+    let (dist, prev, _) = dense_search(start, len, &index_of, |_| false, neighbours_of);
+    (dist, prev)
+}
+
+// Like `dijkstra_to`, for nodes with a dense index in `0..len` given by
+// `index_of`.
+pub fn dijkstra_dense_to<T, P, V>(
+    start: &[T],
+    len: usize,
+    index_of: impl Fn(&T) -> usize,
+    is_target: impl Fn(&T) -> bool,
+    neighbours_of: impl Fn(&T) -> V,
+) -> Option<(P, Vec<T>)>
+where
+    T: Debug + PartialEq + Eq + PartialOrd + Ord + Clone,
+    P: Debug + PartialEq + Eq + PartialOrd + Ord + Default + Clone + Add<Output = P>,
+    V: IntoIterator<Item = (T, P)>,
+{
+    let (mut dist, prev, target) = dense_search(start, len, &index_of, is_target, neighbours_of);
+    let target = target?;
+    let cost = dist[index_of(&target)].take().unwrap();
+    Some((cost, dense_path(target, &prev, &index_of)))
+}
+
+// Runs until all nodes are settled or a target is. Returns costs, previous
+// nodes and the target reached.
+fn dense_search<T, P, V>(
+    start: &[T],
+    len: usize,
+    index_of: &impl Fn(&T) -> usize,
+    is_target: impl Fn(&T) -> bool,
+    neighbours_of: impl Fn(&T) -> V,
+) -> (Vec<Option<P>>, Vec<Option<T>>, Option<T>)
+where
+    T: Debug + PartialEq + Eq + PartialOrd + Ord + Clone,
+    P: Debug + PartialEq + Eq + PartialOrd + Ord + Default + Clone + Add<Output = P>,
+    V: IntoIterator<Item = (T, P)>,
+{
+    let mut dist: Vec<Option<P>> = vec![None; len];
+    let mut prev: Vec<Option<T>> = vec![None; len];
+    let mut todo: BinaryHeap<Reverse<State<T, P>>> = BinaryHeap::default();
+    for s in start {
+        dist[index_of(s)] = Some(P::default());
+        todo.push(Reverse(State {
+            key: s.clone(),
+            prio: P::default(),
+        }));
+    }
+
+    while let Some(Reverse(State { key, prio })) = todo.pop() {
+        if dist[index_of(&key)].as_ref().is_some_and(|d| d < &prio) {
+            // Reached with a lower cost since it was queued.
+            continue;
+        }
+        if is_target(&key) {
+            return (dist, prev, Some(key));
+        }
+        for (neighbour, cost) in neighbours_of(&key) {
+            let alt = prio.clone() + cost;
+            let idx = index_of(&neighbour);
+            if dist[idx].as_ref().map(|p| &alt < p).unwrap_or(true) {
+                dist[idx] = Some(alt.clone());
+                prev[idx] = Some(key.clone());
                 todo.push(Reverse(State {
                     key: neighbour,
                     prio: alt,
@@ -106,10 +440,113 @@ where
             }
         }
     }
+    (dist, prev, None)
+}
+
+// Path from a start to `to` given previous nodes indexed by `index_of`.
+fn dense_path<T: Clone>(to: T, prev: &[Option<T>], index_of: impl Fn(&T) -> usize) -> Vec<T> {
+    let mut path = vec![to];
+    while let Some(p) = &prev[index_of(path.last().unwrap())] {
+        path.push(p.clone());
+    }
+    path.reverse();
+    path
+}
+
+// Like `dijkstra_dense`, for integer costs of at most `max_cost`. Nodes wait in
+// a ring of `max_cost + 1` buckets, one per distance, instead of a heap.
+pub fn dijkstra_dial<T, V>(
+    start: &[T],
+    len: usize,
+    max_cost: usize,
+    index_of: impl Fn(&T) -> usize,
+    neighbours_of: impl Fn(&T) -> V,
+) -> (Vec<Option<usize>>, Vec<Option<T>>)
+where
+    T: Clone,
+    V: IntoIterator<Item = (T, usize)>,
+{
+    let (dist, prev, _) = dial_search(start, len, max_cost, &index_of, |_| false, neighbours_of);
     (dist, prev)
 }
 
+// Like `dijkstra_dense_to`, for integer costs of at most `max_cost`.
+pub fn dijkstra_dial_to<T, V>(
+    start: &[T],
+    len: usize,
+    max_cost: usize,
+    index_of: impl Fn(&T) -> usize,
+    is_target: impl Fn(&T) -> bool,
+    neighbours_of: impl Fn(&T) -> V,
+) -> Option<(usize, Vec<T>)>
+where
+    T: Clone,
+    V: IntoIterator<Item = (T, usize)>,
+{
+    let (dist, prev, target) =
+        dial_search(start, len, max_cost, &index_of, is_target, neighbours_of);
+    let target = target?;
+    let cost = dist[index_of(&target)].unwrap();
+    Some((cost, dense_path(target, &prev, &index_of)))
+}
+
+// Like `dense_search`, with a ring of buckets as the queue.
+fn dial_search<T, V>(
+    start: &[T],
+    len: usize,
+    max_cost: usize,
+    index_of: &impl Fn(&T) -> usize,
+    is_target: impl Fn(&T) -> bool,
+    neighbours_of: impl Fn(&T) -> V,
+) -> (Vec<Option<usize>>, Vec<Option<T>>, Option<T>)
+where
+    T: Clone,
+    V: IntoIterator<Item = (T, usize)>,
+{
+    let mut dist: Vec<Option<usize>> = vec![None; len];
+    let mut prev: Vec<Option<T>> = vec![None; len];
+    // Nodes queued at distance `d` wait in bucket `d % buckets.len()`, which
+    // holds no other distance since every edge is shorter than the ring.
+    let ring = max_cost + 1;
+    let mut buckets: Vec<Vec<T>> = vec![vec![]; ring];
+    let mut queued = 0;
+    for s in start {
+        dist[index_of(s)] = Some(0);
+        buckets[0].push(s.clone());
+        queued += 1;
+    }
+
+    let mut d = 0;
+    while queued > 0 {
+        let bucket = d % ring;
+        while let Some(key) = buckets[bucket].pop() {
+            queued -= 1;
+            if dist[index_of(&key)] != Some(d) {
+                // Reached with a lower cost since it was queued.
+                continue;
+            }
+            if is_target(&key) {
+                return (dist, prev, Some(key));
+            }
+            for (neighbour, cost) in neighbours_of(&key) {
+                assert!(cost <= max_cost, "cost {cost} is over {max_cost}");
+                let alt = d + cost;
+                let idx = index_of(&neighbour);
+                if dist[idx].map(|p| alt < p).unwrap_or(true) {
+                    dist[idx] = Some(alt);
+                    prev[idx] = Some(key.clone());
+                    buckets[alt % ring].push(neighbour);
+                    queued += 1;
+                }
+            }
+        }
+        d += 1;
+    }
+    (dist, prev, None)
+}
+
 #[cfg(test)]
+#[allow(non_local_definitions)]
 mod tests {
     use super::*;
     use proptest::prelude::*;
@@ -188,6 +625,58 @@ mod tests {
         assert_eq!(d_path, b_path);
     }
 
+    #[test]
+    fn layers_from_many_sources() {
+        // Two chains, 0 -> 1 -> ... -> 5 and 10 -> 11 -> 12, both joining at 3.
+        let n_of = |n: &u8| match *n {
+            12 => vec![3],
+            n if n < 5 || (10..12).contains(&n) => vec![n + 1],
+            _ => vec![],
+        };
+        let layers: Vec<Vec<u8>> = bfs_layers(&[0, 10, 0], n_of).collect();
+        assert_eq!(
+            vec![
+                vec![0, 10],
+                vec![1, 11],
+                vec![2, 12],
+                vec![3],
+                vec![4],
+                vec![5]
+            ],
+            layers
+        );
+        let dist = bfs_distances(&[0, 10], n_of);
+        assert_eq!(9, dist.len());
+        assert_eq!((Some(&3), Some(&2)), (dist.get(&3), dist.get(&12)));
+    }
+
+    #[test]
+    fn all_shortest_paths() {
+        // Two paths of cost 2 from A to D, through B or C, and a longer one.
+        let edges = [
+            (A, B, 1),
+            (A, C, 1),
+            (B, D, 1),
+            (C, D, 1),
+            (A, D, 3),
+            (D, E, 1),
+        ];
+        let n_of = |n: &Node| {
+            edges
+                .iter()
+                .filter(|(a, _, _)| a == n)
+                .map(|(_, b, c)| (*b, *c))
+                .collect::<Vec<_>>()
+        };
+        let (cost, path) = dijkstra_to(&[A], |n| *n == D, n_of).unwrap();
+        assert_eq!(2, cost);
+        assert_eq!(3, path.len());
+        let (cost, mut paths) = dijkstra_all_to(&[A], |n| *n == D, n_of).unwrap();
+        paths.sort();
+        assert_eq!((2, vec![vec![A, B, D], vec![A, C, D]]), (cost, paths));
+        assert_eq!(None, dijkstra_to(&[D], |n| *n == A, n_of));
+    }
+
     proptest! {
         #[test]
         fn bfs_and_dijkstra_equal(nodes: HashMap<Node, HashSet<Node>>, start: Node) {
@@ -207,5 +696,76 @@ mod tests {
             }
 
         }
+
+        #[test]
+        fn astar_and_dijkstra_equal(edges: HashMap<(Node, Node), u8>, start: Node) {
+            let n_of = |n: &Node| edges.iter().filter(|((a, _), _)| a == n).map(|((_, b), c)| (*b, *c as u32)).collect::<Vec<_>>();
+            let (d_dist, _) = dijkstra(&[start], n_of);
+            for target in ALL {
+                // Admissible: every edge into `target` costs at least its cheapest one.
+                let min_in = edges.iter().filter(|((_, b), _)| *b == target).map(|(_, c)| *c as u32).min().unwrap_or(0);
+                let h = |n: &Node| if *n == target { 0 } else { min_in };
+                match astar(&[start], |n| *n == target, n_of, h) {
+                    Some((cost, p)) => {
+                        assert_eq!(Some(&cost), d_dist.get(&target));
+                        assert_eq!((Some(&start), Some(&target)), (p.first(), p.last()));
+                        let along: u32 = p.windows(2).map(|w| *edges.get(&(w[0], w[1])).unwrap() as u32).sum();
+                        assert_eq!(cost, along);
+                    }
+                    None => assert!(!d_dist.contains_key(&target)),
+                }
+            }
+        }
+
+        #[test]
+        fn dijkstra_to_finds_all_shortest(edges: HashMap<(Node, Node), u8>, start: Node, target: Node) {
+            let n_of = |n: &Node| edges.iter().filter(|((a, _), _)| a == n).map(|((_, b), c)| (*b, *c as u32 + 1)).collect::<Vec<_>>();
+            let (d_dist, _) = dijkstra(&[start], n_of);
+            let cost_of = |p: &[Node]| p.windows(2).map(|w| *edges.get(&(w[0], w[1])).unwrap() as u32 + 1).sum::<u32>();
+            match (dijkstra_to(&[start], |n| *n == target, n_of), dijkstra_all_to(&[start], |n| *n == target, n_of)) {
+                (Some((cost, path)), Some((all_cost, paths))) => {
+                    assert_eq!(Some(&cost), d_dist.get(&target));
+                    assert_eq!(cost, all_cost);
+                    assert!(paths.contains(&path));
+                    assert!(paths.iter().all(|p| cost_of(p) == cost && p.last() == Some(&target)));
+                    assert_eq!(paths.len(), paths.iter().collect::<HashSet<_>>().len());
+                }
+                (None, None) => assert!(!d_dist.contains_key(&target)),
+                _ => panic!(),
+            }
+        }
+
+        #[test]
+        fn dense_and_dijkstra_equal(edges: HashMap<(Node, Node), u8>, start: Node) {
+            let n_of = |n: &Node| edges.iter().filter(|((a, _), _)| a == n).map(|((_, b), c)| (*b, *c as usize)).collect::<Vec<_>>();
+            let index_of = |n: &Node| *n as usize;
+            let (d_dist, _) = dijkstra(&[start], n_of);
+            let (dense_dist, dense_prev) = dijkstra_dense(&[start], ALL.len(), index_of, n_of);
+            let (dial_dist, dial_prev) = dijkstra_dial(&[start], ALL.len(), u8::MAX as usize, index_of, n_of);
+            let bfs_dist = bfs_dense(&[start], ALL.len(), index_of, |n| n_of(n).into_iter().map(|(n, _)| n));
+            let b_dist = bfs_distances(&[start], |n| n_of(n).into_iter().map(|(n, _)| n));
+            for n in ALL {
+                assert_eq!(d_dist.get(&n), dense_dist[index_of(&n)].as_ref());
+                assert_eq!(d_dist.get(&n), dial_dist[index_of(&n)].as_ref());
+                assert_eq!(b_dist.get(&n), bfs_dist[index_of(&n)].as_ref());
+                let cost_of = |p: &[Node]| p.windows(2).map(|w| *edges.get(&(w[0], w[1])).unwrap() as usize).sum::<usize>();
+                for found in [
+                    dijkstra_dense_to(&[start], ALL.len(), index_of, |m| *m == n, n_of),
+                    dijkstra_dial_to(&[start], ALL.len(), u8::MAX as usize, index_of, |m| *m == n, n_of),
+                ] {
+                    assert_eq!(d_dist.get(&n), found.as_ref().map(|(cost, _)| cost));
+                    if let Some((cost, path)) = found {
+                        assert_eq!((Some(&start), Some(&n)), (path.first(), path.last()));
+                        assert_eq!(cost, cost_of(&path));
+                    }
+                }
+                // Previous nodes lie on a shortest path.
+                for prev in [&dense_prev, &dial_prev] {
+                    if let Some(p) = &prev[index_of(&n)] {
+                        assert_eq!(dense_dist[index_of(&n)], Some(dense_dist[index_of(p)].unwrap() + *edges.get(&(*p, n)).unwrap() as usize));
+                    }
+                }
+            }
+        }
     }
 }
@@ -1,6 +1,11 @@
-use std::fmt::Debug;
+use anyhow::{anyhow, bail, ensure, Context, Result};
+use itertools::Either;
+use regex::Regex;
+use std::fmt::{Debug, Display};
 use std::str::FromStr;
 
+/// Parses tokens of `input` separated by `sep`, or by whitespace when `sep` is
+/// `None`. Tokens that fail to parse are skipped, see [`try_tokens`].
 pub fn tokens<T>(input: &str, sep: Option<&str>) -> Vec<T>
 where
     T: FromStr + Debug,
@@ -20,6 +25,8 @@ where
     }
 }
 
+/// Parses groups of tokens of `input` separated by `sep`, with tokens of a
+/// group separated as in [`tokens`]. See [`try_token_groups`].
 pub fn token_groups<T>(input: &str, sep: &str, inner_sep: Option<&str>) -> Vec<Vec<T>>
 where
     T: FromStr + Debug,
@@ -31,3 +38,261 @@ where
         .map(|sub| tokens(sub, inner_sep))
         .collect()
 }
+
+fn split<'a>(input: &'a str, sep: Option<&'a str>) -> impl Iterator<Item = &'a str> {
+    match sep {
+        Some(sep) => Either::Left(input.split(sep).filter(|v| !v.is_empty())),
+        None => Either::Right(input.split_whitespace()),
+    }
+}
+
+// Line and column, both counted from 1, of `token`, a slice of `input`.
+fn position(input: &str, token: &str) -> (usize, usize) {
+    let before = &input[..token.as_ptr() as usize - input.as_ptr() as usize];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+fn parse<T>(input: &str, token: &str) -> Result<T>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Display,
+{
+    token.parse().map_err(|e| {
+        let (line, column) = position(input, token);
+        if input.contains('\n') {
+            anyhow!("line {line}, column {column}: cannot parse '{token}': {e}")
+        } else {
+            anyhow!("column {column}: cannot parse '{token}': {e}")
+        }
+    })
+}
+
+/// Like [`tokens`], but fails on the first token that does not parse.
+pub fn try_tokens<T>(input: &str, sep: Option<&str>) -> Result<Vec<T>>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Display,
+{
+    split(input, sep).map(|token| parse(input, token)).collect()
+}
+
+/// Like [`token_groups`], but fails on the first token that does not parse.
+pub fn try_token_groups<T>(input: &str, sep: &str, inner_sep: Option<&str>) -> Result<Vec<Vec<T>>>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Display,
+{
+    input
+        .split(sep)
+        .filter(|l| !l.is_empty())
+        .map(|group| {
+            split(group, inner_sep)
+                .map(|token| parse(input, token))
+                .collect()
+        })
+        .collect()
+}
+
+/// Parses each non empty line of `input` with `f`, adding the line number to
+/// its errors.
+pub fn try_lines<T>(input: &str, mut f: impl FnMut(&str) -> Result<T>) -> Result<Vec<T>> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| f(line).with_context(|| format!("line {}", i + 1)))
+        .collect()
+}
+
+/// Value built from the fields of a line matched by a [`Pattern`].
+///
+/// Implemented for tuples of up to six types that implement [`FromStr`].
+pub trait FromFields: Sized {
+    /// Number of fields.
+    const LEN: usize;
+
+    /// Parses `fields`, which are slices of `input`, in order.
+    fn from_fields(input: &str, fields: &[&str]) -> Result<Self>;
+}
+
+macro_rules! from_fields_for_tuples {
+    ($(($($t:ident),+)),+) => {
+        $(
+            impl<$($t),+> FromFields for ($($t,)+)
+            where
+                $($t: FromStr, <$t as FromStr>::Err: Display,)+
+            {
+                const LEN: usize = [$(stringify!($t)),+].len();
+
+                fn from_fields(input: &str, fields: &[&str]) -> Result<Self> {
+                    let mut fields = fields.iter();
+                    Ok(($(parse::<$t>(input, fields.next().unwrap())?,)+))
+                }
+            }
+        )+
+    };
+}
+
+from_fields_for_tuples!(
+    (A),
+    (A, B),
+    (A, B, C),
+    (A, B, C, D),
+    (A, B, C, D, E),
+    (A, B, C, D, E, F)
+);
+
+/// Pattern of a line, like `"{} = ({}, {})"`, where each `{}` is a field and
+/// `{{` and `}}` stand for literal braces. A space matches any non empty run
+/// of whitespace.
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    source: String,
+    regex: Regex,
+    len: usize,
+}
+
+impl Pattern {
+    pub fn new(pattern: &str) -> Result<Self> {
+        let mut regex = "^".to_owned();
+        let mut literal = String::new();
+        let mut len = 0;
+        let mut chars = pattern.chars().peekable();
+        while let Some(c) = chars.next() {
+            match (c, chars.peek()) {
+                ('{', Some('{')) | ('}', Some('}')) => {
+                    chars.next();
+                    literal.push(c);
+                }
+                ('{', Some('}')) => {
+                    chars.next();
+                    regex += &regex::escape(&literal).replace(' ', r"\s+");
+                    regex += "(.+?)";
+                    literal.clear();
+                    len += 1;
+                }
+                ('{' | '}', _) => bail!("unmatched '{c}' in pattern '{pattern}'"),
+                _ => literal.push(c),
+            }
+        }
+        regex += &regex::escape(&literal).replace(' ', r"\s+");
+        regex += "$";
+        Ok(Self {
+            source: pattern.to_owned(),
+            regex: Regex::new(&regex)?,
+            len,
+        })
+    }
+
+    // Parses the fields of `line`, a slice of `input`.
+    fn fields<T: FromFields>(&self, input: &str, line: &str) -> Result<Option<T>> {
+        ensure!(
+            self.len == T::LEN,
+            "pattern '{}' has {} fields, but {} are expected",
+            self.source,
+            self.len,
+            T::LEN
+        );
+        let Some(captures) = self.regex.captures(line) else {
+            return Ok(None);
+        };
+        let fields: Vec<&str> = captures
+            .iter()
+            .skip(1)
+            .map(|c| c.unwrap().as_str())
+            .collect();
+        T::from_fields(input, &fields).map(Some)
+    }
+
+    /// Parses the fields of `s`, which must match the whole pattern.
+    pub fn parse<T: FromFields>(&self, s: &str) -> Result<T> {
+        match self.fields(s, s)? {
+            Some(value) => Ok(value),
+            None => bail!("'{s}' does not match '{}'", self.source),
+        }
+    }
+}
+
+/// Parses each non empty line of `input` with `pattern`, for example
+/// `parse_lines::<(String, String, String)>(input, "{} = ({}, {})")`.
+pub fn parse_lines<T: FromFields>(input: &str, pattern: &str) -> Result<Vec<T>> {
+    let pattern = Pattern::new(pattern)?;
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| match pattern.fields(input, line)? {
+            Some(value) => Ok(value),
+            None => bail!(
+                "line {}: '{line}' does not match '{}'",
+                i + 1,
+                pattern.source
+            ),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_position_of_bad_token() {
+        assert_eq!(vec![1, 2, 3], try_tokens::<i64>("1 2\n 3", None).unwrap());
+        assert_eq!(
+            vec![vec![1, 2], vec![3]],
+            try_token_groups::<i64>("1,2\n3\n", "\n", Some(",")).unwrap()
+        );
+        assert_eq!(
+            "line 2, column 3: cannot parse 'x4': invalid digit found in string",
+            try_token_groups::<i64>("1 2\n3 x4 5", "\n", None)
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            "column 3: cannot parse '-': invalid digit found in string",
+            try_tokens::<i64>("1 - 3", None).unwrap_err().to_string()
+        );
+        assert_eq!(vec![1, 3], tokens::<i64>("1 x 3", None));
+    }
+
+    #[test]
+    fn parses_lines_with_pattern() {
+        assert_eq!(
+            vec![
+                ("AAA".to_owned(), "BBB".to_owned(), 'C'),
+                ("BBB".to_owned(), "AAA".to_owned(), 'Z'),
+            ],
+            parse_lines::<(String, String, char)>(
+                "AAA = (BBB, C)\nBBB =  (AAA, Z)\n",
+                "{} = ({}, {})"
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            (787, -2),
+            Pattern::new("{{x={},y={}}}")
+                .unwrap()
+                .parse::<(i64, i64)>("{x=787,y=-2}")
+                .unwrap()
+        );
+        assert_eq!(
+            "line 2: 'a - b' does not match '{} -> {}'",
+            parse_lines::<(String, String)>("a -> b\na - b", "{} -> {}")
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            "line 2, column 6: cannot parse 'x': invalid digit found in string",
+            parse_lines::<(u8, u8)>("1 -> 2\n3 -> x", "{} -> {}")
+                .unwrap_err()
+                .to_string()
+        );
+        assert!(parse_lines::<(u8,)>("1 -> 2", "{} -> {}").is_err());
+        assert!(Pattern::new("{x}").is_err());
+    }
+}
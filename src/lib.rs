@@ -1,30 +1,40 @@
+pub mod alloc;
+pub mod answers;
+pub mod baseline;
+pub mod client;
 pub mod dijkstra;
 pub mod input;
 pub mod pos;
+pub mod report;
+pub mod scaffold;
+pub mod select;
+pub mod solution;
+pub mod stats;
+pub mod submissions;
 pub mod vec;
+pub mod year2023;
 
-pub mod day01;
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
-pub mod day06;
-pub mod day07;
-pub mod day08;
-pub mod day09;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day14;
-pub mod day15;
-pub mod day16;
-pub mod day17;
-pub mod day18;
-pub mod day19;
-pub mod day20;
-pub mod day21;
-pub mod day22;
-pub mod day23;
-pub mod day24;
-pub mod day25;
+use solution::DynSolution;
+
+/// Calendar of a single year.
+pub struct Year {
+    pub year: u16,
+    /// All days, in order.
+    pub days: &'static [&'static dyn DynSolution],
+}
+
+/// All years, in order.
+pub const YEARS: &[Year] = &[Year {
+    year: 2023,
+    days: year2023::SOLUTIONS,
+}];
+
+/// Returns the calendar of `year`.
+pub fn year(year: u16) -> Option<&'static Year> {
+    YEARS.iter().find(|y| y.year == year)
+}
+
+/// Returns the solution of `day` (counted from 1) of `year`.
+pub fn solution(year: u16, day: usize) -> Option<&'static dyn DynSolution> {
+    self::year(year)?.days.get(day.checked_sub(1)?).copied()
+}
@@ -1,7 +1,25 @@
-use anyhow::Result;
+use anyhow::{bail, ensure, Context, Result};
+use aoc23::alloc::{self, AllocStats};
+use aoc23::answers::{Answers, Check};
+use aoc23::baseline::Baseline;
+use aoc23::client::{self, Client, Verdict};
+use aoc23::report::{self, DayReport, Format, Status, Timings};
+use aoc23::scaffold;
+use aoc23::select::{is_selected, Days, InputOverride, Part, Source};
+use aoc23::solution::{Answer, DynSolution, Params, ParsedInput};
+use aoc23::stats::{median, Stats};
+use aoc23::submissions::{self, Submissions};
+use aoc23::{Year, YEARS};
+use itertools::Itertools;
 use memmap::MmapOptions;
+use rustc_hash::FxHashMap;
+use std::any::Any;
 use std::fs::File;
-use std::path::PathBuf;
+use std::io::{IsTerminal, Read, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
 use std::time::Duration;
 use std::time::Instant;
 use structopt::StructOpt;
@@ -12,117 +30,693 @@ struct Opt {
     #[structopt(short, long)]
     skip_verification: bool,
 
+    /// Year to run, the latest one by default.
     #[structopt(short, long)]
-    day_to_run: Option<usize>,
+    year: Option<u16>,
 
+    /// Days to run, like `1,5,10-15`. All days by default.
     #[structopt(short, long)]
-    input_file: Option<PathBuf>,
+    days: Option<Days>,
+
+    /// Run only this part of each day.
+    #[structopt(short, long)]
+    part: Option<Part>,
+
+    /// Input of a day as `DAY=PATH`, or only `PATH` when a single day is
+    /// selected. `-` reads the input from stdin. Can be repeated. Without
+    /// `--days`, only days given an input run.
+    #[structopt(short, long = "input", number_of_values = 1)]
+    inputs: Vec<InputOverride>,
 
     #[structopt(long)]
     skip_output: bool,
+
+    /// File with expected answers, keyed by input file name. Defaults to
+    /// `inputs/<year>/answers`.
+    #[structopt(long)]
+    answers_file: Option<PathBuf>,
+
+    /// Output format: text, json or csv. Machine readable formats emit one
+    /// record per day once all days are done.
+    #[structopt(long, default_value = "text")]
+    format: Format,
+
+    /// Number of timed runs of each day.
+    #[structopt(long, default_value = "1")]
+    runs: usize,
+
+    /// Number of untimed runs of each day before the timed ones.
+    #[structopt(long, default_value = "0")]
+    warmup: usize,
+
+    /// Compare timings with a baseline saved earlier with --save-baseline.
+    #[structopt(long)]
+    baseline: Option<PathBuf>,
+
+    /// Save timings of this run as a baseline.
+    #[structopt(long)]
+    save_baseline: Option<PathBuf>,
+
+    /// Slowdown of compute time, in percent, flagged as a regression.
+    #[structopt(long, default_value = "10")]
+    regression_threshold: f64,
+
+    /// Seconds after which a day is reported as timed out. Its thread keeps
+    /// running in the background until the runner exits.
+    #[structopt(long)]
+    timeout: Option<f64>,
+
+    /// Run all selected days at once on the rayon thread pool.
+    #[structopt(long)]
+    parallel: bool,
+
+    /// Exit with non-zero status when any day regressed.
+    #[structopt(long)]
+    fail_on_regression: bool,
+
+    #[structopt(subcommand)]
+    command: Option<Command>,
 }
 
-fn median(array: &[Duration]) -> Duration {
-    if (array.len() % 2) == 0 {
-        let ind_left = array.len() / 2 - 1;
-        let ind_right = array.len() / 2;
-        (array[ind_left] + array[ind_right]).div_f64(2.0)
-    } else {
-        array[array.len() / 2]
+#[derive(StructOpt, Debug)]
+enum Command {
+    /// Create a module for the next day from a template, register it and
+    /// add placeholders for its input and example input. Day 1 of a year
+    /// without solutions creates and registers the module of the year.
+    NewDay { day: usize },
+    /// Download the input of a day into `inputs/<year>/`, unless it is
+    /// already there. Needs a session token in AOC_SESSION or in
+    /// `~/.config/aoc23/session`.
+    Fetch {
+        #[structopt(long)]
+        day: usize,
+    },
+    /// Submit an answer, or the one computed from the input of the day, and
+    /// record the response in `inputs/<year>/submissions.json`. Answers that
+    /// were already rejected or are out of known bounds are not submitted.
+    Submit {
+        #[structopt(long)]
+        day: usize,
+        #[structopt(long)]
+        part: Part,
+        answer: Option<String>,
+    },
+}
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: alloc::CountingAllocator = alloc::CountingAllocator;
+
+// Allocations are only counted with the counting allocator installed.
+const ALLOC_STATS: bool = cfg!(feature = "alloc-stats");
+
+// Stack size of threads running days. Same as the main thread, which used
+// to run all days.
+const STACK_SIZE: usize = 8 << 20;
+
+// Answers of a day, with timings and time with i/o of each run, and
+// allocations made by the first run.
+struct Measured {
+    answers: [Answer; 2],
+    times: Vec<(Timings, Duration)>,
+    allocs: AllocStats,
+}
+
+enum Outcome<T> {
+    Done(T),
+    Failed(Status, String),
+}
+
+impl<T> Outcome<T> {
+    fn new(result: std::thread::Result<Result<T>>) -> Self {
+        match result {
+            Ok(Ok(r)) => Outcome::Done(r),
+            Ok(Err(e)) => Outcome::Failed(Status::Error, format!("{e:#}")),
+            Err(panic) => Outcome::Failed(Status::Panicked, panic_message(panic)),
+        }
+    }
+
+    fn timed_out(timeout: Duration) -> Self {
+        Outcome::Failed(Status::TimedOut, format!("no answers after {timeout:.1?}"))
+    }
+}
+
+fn panic_message(panic: Box<dyn Any + Send>) -> String {
+    match panic.downcast::<String>() {
+        Ok(s) => *s,
+        Err(panic) => match panic.downcast::<&str>() {
+            Ok(s) => s.to_string(),
+            Err(_) => "unknown panic".to_owned(),
+        },
+    }
+}
+
+// Runs `f` on its own thread, so that a panic or a hang fails a single day
+// instead of the whole run. Thread that timed out is left running in the
+// background, as there is no way to stop it.
+fn isolated<T: Send + 'static>(
+    timeout: Option<Duration>,
+    f: impl FnOnce() -> Result<T> + Send + 'static,
+) -> Outcome<T> {
+    let (tx, rx) = mpsc::channel();
+    std::thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            let _ = tx.send(panic::catch_unwind(AssertUnwindSafe(f)));
+        })
+        .expect("failed to spawn a thread");
+    match rx.recv_timeout(timeout.unwrap_or(Duration::MAX)) {
+        Ok(result) => Outcome::new(result),
+        Err(RecvTimeoutError::Timeout) => Outcome::timed_out(timeout.unwrap()),
+        Err(RecvTimeoutError::Disconnected) => unreachable!("thread always sends a result"),
+    }
+}
+
+// Runs all days at once, as tasks on the global rayon pool. Days that use
+// rayon themselves share the same pool, so nested parallelism never runs more
+// threads than the pool has; such days only compete with others for workers.
+// Timeout counts from the start of all days, and days that timed out are left
+// running in the background.
+fn run_parallel(
+    year: &'static Year,
+    inputs: Vec<(usize, DayInput, Params)>,
+    part: Option<Part>,
+    warmup: usize,
+    runs: usize,
+    timeout: Option<Duration>,
+) -> Vec<(usize, String, Outcome<Measured>)> {
+    let start = Instant::now();
+    let (tx, rx) = mpsc::channel();
+    let mut names = vec![];
+    for (day, input, params) in inputs {
+        names.push((day, input.name()));
+        let tx = tx.clone();
+        rayon::spawn(move || {
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                measure(year.days[day - 1], &input, &params, part, warmup, runs)
+            }));
+            let _ = tx.send((day, result));
+        });
+    }
+    drop(tx);
+
+    let mut outcomes: FxHashMap<usize, Outcome<Measured>> = Default::default();
+    let deadline = timeout.map(|timeout| start + timeout);
+    loop {
+        let received = match deadline {
+            Some(deadline) => rx
+                .recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                .ok(),
+            None => rx.recv().ok(),
+        };
+        let Some((day, result)) = received else {
+            break;
+        };
+        outcomes.insert(day, Outcome::new(result));
     }
+    names
+        .into_iter()
+        .map(|(day, name)| {
+            let outcome = outcomes
+                .remove(&day)
+                .unwrap_or_else(|| Outcome::timed_out(timeout.unwrap()));
+            (day, name, outcome)
+        })
+        .collect()
+}
+
+// Input of a day. Stdin is read upfront, since it can't be read again by
+// later runs.
+enum DayInput {
+    File(PathBuf),
+    Stdin(Arc<str>),
+}
+
+impl DayInput {
+    fn name(&self) -> String {
+        match self {
+            DayInput::File(path) => path.file_name().unwrap().to_string_lossy().into_owned(),
+            DayInput::Stdin(_) => "-".to_owned(),
+        }
+    }
+}
+
+// Downloads inputs of `days` into `inputs/`.
+fn fetch(year: u16, days: &[usize]) -> Result<Vec<PathBuf>> {
+    let client = Client::new(client::session_token()?);
+    days.iter()
+        .map(|day| client::fetch_input(&client, Path::new("inputs"), year, *day))
+        .collect()
+}
+
+fn submissions_path(year: u16) -> PathBuf {
+    PathBuf::from(format!("inputs/{year}/submissions.json"))
+}
+
+// Submits `answer`, computed from the default input when not given, and logs
+// the response.
+fn submit(year: &Year, day: usize, part: Part, answer: Option<String>) -> Result<()> {
+    let solution =
+        aoc23::solution(year.year, day).with_context(|| format!("day {day} is not solved yet"))?;
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let path = client::input_path(Path::new("inputs"), year.year, day);
+            let input = std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            let (_, answers) = run(solution, &input, &Params::default(), Some(part))?;
+            let answer = answers[part.number() - 1].to_string();
+            ensure!(answer != "-", "day {day} has no part {}", part.number());
+            answer
+        }
+    };
+    let path = submissions_path(year.year);
+    let mut log = Submissions::load(&path)?;
+    let client = Client::new(client::session_token()?);
+    let submission =
+        submissions::submit(&client, &mut log, year.year, day, part.number(), &answer)?;
+    log.save(&path)?;
+    println!("{}", submission.message);
+    ensure!(
+        submission.verdict == Verdict::Correct,
+        "{answer} is {}",
+        submission.verdict
+    );
+    Ok(())
+}
+
+// Asks whether to download default inputs of selected days that are missing,
+// when running interactively.
+fn offer_fetch(year: u16, inputs: &[(usize, DayInput)]) {
+    if !std::io::stdin().is_terminal() {
+        return;
+    }
+    let missing = inputs
+        .iter()
+        .filter(|(day, input)| match input {
+            DayInput::File(path) => {
+                !client::is_cached(path)
+                    && *path == client::input_path(Path::new("inputs"), year, *day)
+            }
+            DayInput::Stdin(_) => false,
+        })
+        .map(|(day, _)| *day)
+        .collect_vec();
+    if missing.is_empty() {
+        return;
+    }
+    print!(
+        "Inputs of days {} are missing, fetch them? [y/N] ",
+        missing.iter().map(|d| format!("{d:02}")).join(", ")
+    );
+    let _ = std::io::stdout().flush();
+    let mut answer = String::new();
+    let _ = std::io::stdin().read_line(&mut answer);
+    if answer.trim().eq_ignore_ascii_case("y") {
+        if let Err(e) = fetch(year, &missing) {
+            eprintln!("{e:#}");
+        }
+    }
+}
+
+// Resolves inputs of selected days, with defaults from `inputs/<year>/`.
+fn day_inputs(opt: &Opt, year: &Year) -> Result<Vec<(usize, DayInput)>> {
+    let mut sources: FxHashMap<usize, Source> = (1..=year.days.len())
+        .filter(|day| is_selected(opt.days.as_ref(), &opt.inputs, *day))
+        .map(|day| {
+            let path = format!("inputs/{}/day{day:02}", year.year);
+            (day, Source::File(path.into()))
+        })
+        .collect();
+    for input in &opt.inputs {
+        let day = match input.day {
+            Some(day) => day,
+            None => opt
+                .days
+                .as_ref()
+                .and_then(|days| days.single())
+                .context("input without a day requires a single day selected with --days")?,
+        };
+        if sources.insert(day, input.source.clone()).is_none() {
+            bail!("input given for day {day}, which is not selected or not solved yet");
+        }
+    }
+    if sources.values().filter(|s| **s == Source::Stdin).count() > 1 {
+        bail!("stdin can be the input of only one day");
+    }
+    sources
+        .into_iter()
+        .map(|(day, source)| {
+            let input = match source {
+                Source::File(path) => DayInput::File(path),
+                Source::Stdin => {
+                    let mut s = String::new();
+                    std::io::stdin()
+                        .read_to_string(&mut s)
+                        .context("Failed to read input from stdin")?;
+                    DayInput::Stdin(s.into())
+                }
+            };
+            Ok((day, input))
+        })
+        .collect::<Result<Vec<_>>>()
+        .map(|mut inputs| {
+            inputs.sort_unstable_by_key(|(day, _)| *day);
+            inputs
+        })
+}
+
+// Runs a day `warmup + runs` times. Returns answers of the first run, with
+// timings and time with i/o of runs after the warmup.
+fn measure(
+    solution: &dyn DynSolution,
+    input: &DayInput,
+    params: &Params,
+    part: Option<Part>,
+    warmup: usize,
+    runs: usize,
+) -> Result<Measured> {
+    let input_file = match input {
+        DayInput::File(path) => Some(
+            File::open(path)
+                .with_context(|| format!("Failed to open input: {}", path.display()))?,
+        ),
+        DayInput::Stdin(_) => None,
+    };
+    let mut result = None;
+    let mut solution_times = vec![];
+    for i in 0..warmup + runs.max(1) {
+        let start = Instant::now();
+        let mapped_input = match &input_file {
+            Some(file) => Some(unsafe { MmapOptions::new().map(file)? }),
+            None => None,
+        };
+        let text = match (&mapped_input, input) {
+            (Some(mapped_input), _) => std::str::from_utf8(mapped_input)?,
+            (None, DayInput::Stdin(text)) => text,
+            (None, DayInput::File(_)) => unreachable!("file inputs are mapped"),
+        };
+        let read = start.elapsed();
+
+        let (run_result, allocs) = alloc::measure(|| run(solution, text, params, part));
+        let (mut timings, answers) = run_result?;
+        let with_io = start.elapsed();
+        timings.read = read;
+        result.get_or_insert((answers, allocs));
+        if i >= warmup {
+            solution_times.push((timings, with_io));
+        }
+    }
+    let (answers, allocs) = result.unwrap();
+    Ok(Measured {
+        answers,
+        times: solution_times,
+        allocs,
+    })
+}
+
+// Returns answers and time spent in parsing and in each part. Reading is
+// timed by the caller. Part that is not selected gives no answer.
+fn run(
+    solution: &dyn DynSolution,
+    input: &str,
+    params: &Params,
+    part: Option<Part>,
+) -> Result<(Timings, [Answer; 2])> {
+    let s = Instant::now();
+    let parsed = solution.parse(input, params)?;
+    let parse = s.elapsed();
+
+    let timed = |f: &dyn Fn(&ParsedInput) -> Result<Answer>| -> Result<(Answer, Duration)> {
+        let s = Instant::now();
+        let answer = f(&parsed)?;
+        Ok((answer, s.elapsed()))
+    };
+    let skipped = (Answer::None, Duration::ZERO);
+    let s = Instant::now();
+    let [(part1, part1_time), (part2, part2_time)] = match part.map(Part::number) {
+        None => solution.solve_timed(&parsed)?,
+        Some(1) => [timed(&|p| solution.part1(p))?, skipped],
+        Some(_) => [skipped, timed(&|p| solution.part2(p))?],
+    };
+    let solve = s.elapsed();
+
+    let timings = Timings {
+        read: Duration::ZERO,
+        parse,
+        part1: part1_time,
+        part2: part2_time,
+        solve,
+    };
+    Ok((timings, [part1, part2]))
 }
 
 fn d2s(d: Duration) -> String {
     format!("{:.1?}", d)
 }
 
+fn bytes(b: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = b as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1}{}", UNITS[unit])
+}
+
 fn main() {
     let opt = Opt::from_args();
+    if let Some(Command::NewDay { day }) = opt.command {
+        // Runs before looking up the year, as it may start a new one.
+        let year = opt.year.unwrap_or_else(|| YEARS.last().unwrap().year);
+        match scaffold::new_day(Path::new("."), year, day) {
+            Ok(files) => files.iter().for_each(|f| println!("{}", f.display())),
+            Err(e) => {
+                eprintln!("{e:#}");
+                std::process::exit(1)
+            }
+        }
+        return;
+    }
+    let year = match opt.year {
+        Some(year) => aoc23::year(year).unwrap_or_else(|| {
+            eprintln!(
+                "No solutions for year {year}, known years: {}",
+                YEARS.iter().map(|y| y.year).join(", ")
+            );
+            std::process::exit(1)
+        }),
+        None => YEARS.last().unwrap(),
+    };
+    if let Some(command) = &opt.command {
+        let result = match command {
+            Command::NewDay { .. } => unreachable!("new-day runs before the year is known"),
+            Command::Fetch { day } => fetch(year.year, &[*day])
+                .map(|files| files.iter().for_each(|f| println!("{}", f.display()))),
+            Command::Submit { day, part, answer } => submit(year, *day, *part, answer.clone()),
+        };
+        if let Err(e) = result {
+            eprintln!("{e:#}");
+            std::process::exit(1)
+        }
+        return;
+    }
+    rayon::ThreadPoolBuilder::new()
+        .stack_size(STACK_SIZE)
+        .build_global()
+        .expect("failed to configure rayon thread pool");
+    // Answers are loaded even when not verified, for parameters of examples.
+    let answers = {
+        let path = match &opt.answers_file {
+            Some(path) => path.clone(),
+            None => PathBuf::from(format!("inputs/{}/answers", year.year)),
+        };
+        let mut answers = match Answers::load(&path) {
+            Ok(answers) => answers,
+            Err(_) if opt.skip_verification => Answers::default(),
+            Err(e) => {
+                eprintln!("{e:#}");
+                std::process::exit(1)
+            }
+        };
+        match Submissions::load(&submissions_path(year.year)) {
+            Ok(submissions) => submissions.confirm(&mut answers),
+            Err(e) => eprintln!("{e:#}"),
+        }
+        answers
+    };
+    let baseline = match &opt.baseline {
+        Some(path) => Baseline::load(path).unwrap_or_else(|e| {
+            eprintln!("{e:#}");
+            std::process::exit(1)
+        }),
+        None => Baseline::default(),
+    };
+    let inputs = day_inputs(&opt, year).unwrap_or_else(|e| {
+        eprintln!("{e:#}");
+        std::process::exit(1)
+    });
+    let text = opt.format == Format::Text;
+    if text {
+        offer_fetch(year.year, &inputs);
+    }
+    let mut regressed_days = vec![];
+    let mut reports = vec![];
+    let mut failed_days = vec![];
+    let mut unfinished_days = vec![];
     let mut times = vec![];
     let mut times_io = vec![];
 
-    let solutions: Vec<&dyn Fn(&str, bool, bool) -> Result<Duration>> = vec![
-        &aoc23::day01::solve,
-        &aoc23::day02::solve,
-        &aoc23::day03::solve,
-        &aoc23::day04::solve,
-        &aoc23::day05::solve,
-        &aoc23::day06::solve,
-        &aoc23::day07::solve,
-        &aoc23::day08::solve,
-        &aoc23::day09::solve,
-        &aoc23::day10::solve,
-        &aoc23::day11::solve,
-        &aoc23::day12::solve,
-        &aoc23::day13::solve,
-        &aoc23::day14::solve,
-        &aoc23::day15::solve,
-        &aoc23::day16::solve,
-        &aoc23::day17::solve,
-        &aoc23::day18::solve,
-        &aoc23::day19::solve,
-        &aoc23::day20::solve,
-        &aoc23::day21::solve,
-        &aoc23::day22::solve,
-        &aoc23::day23::solve,
-        &aoc23::day24::solve,
-        &aoc23::day25::solve,
-    ];
-
     let mut running_sum_compute = Duration::from_secs(0);
     let mut running_sum_io = Duration::from_secs(0);
-    for (i, solution) in solutions.iter().enumerate() {
-        if Some(i + 1) == opt.day_to_run || opt.day_to_run.is_none() {
-            let input_file = match &opt.input_file {
-                Some(path) => File::open(path).unwrap(),
-                None => {
-                    let path = format!("inputs/day{:02}", i + 1);
-                    File::open(&path).expect(&format!("Failed to open input: {path}"))
-                }
-            };
-
-            let mut solution_times = vec![];
-            #[cfg(debug_assertions)]
-            let loops = 1;
-            #[cfg(not(debug_assertions))]
-            let loops = 1;
-            for i in 0..loops {
-                let start = Instant::now();
-                let mapped_input = unsafe { MmapOptions::new().map(&input_file).unwrap() };
-                let input = std::str::from_utf8(&mapped_input).unwrap();
-
-                let t = match solution(
-                    input,
-                    !opt.skip_verification,
-                    if i == 0 { !opt.skip_output } else { false },
-                ) {
-                    Ok(t) => t,
-                    Err(e) => {
-                        eprintln!("Solution {i} failed: {e}");
-                        continue;
-                    }
-                };
-
-                solution_times.push((t, start.elapsed()));
-                if t > Duration::from_secs(1) {
-                    break;
+    let mut report_day = |day: usize, input_name: String, outcome: Outcome<Measured>| {
+        let (result, solution_times, allocs) = match outcome {
+            Outcome::Done(m) => (m.answers, m.times, m.allocs),
+            Outcome::Failed(status, error) => {
+                if text {
+                    println!("Day {:02} {status}: {error}", day);
                 }
+                unfinished_days.push((day, status));
+                reports.push(DayReport::unfinished(
+                    year.year, day, input_name, status, error,
+                ));
+                return;
             }
-            let (t, solution_with_io) = solution_times.into_iter().min().unwrap();
-            running_sum_compute += t;
-            running_sum_io += solution_with_io;
+        };
+        let mut check = Check::Missing;
+        if !opt.skip_verification {
+            check = answers.check(&input_name, &result);
+            if let Some(part) = opt.part {
+                check = check.only_part(part.number());
+            }
+        }
+        if text && !opt.skip_output {
+            for answer in result.iter().filter(|a| **a != Answer::None) {
+                println!("\t{}", answer);
+            }
+        }
+        let compute_times = solution_times.iter().map(|(t, _)| t.solve).collect_vec();
+        let stats = Stats::new(&compute_times).unwrap();
+        let t = stats.min;
+        let solution_with_io = solution_times.iter().map(|(_, io)| *io).min().unwrap();
+        let (timings, _) = solution_times.iter().min_by_key(|(t, _)| t.solve).unwrap();
+        running_sum_compute += t;
+        running_sum_io += solution_with_io;
+        reports.push(
+            DayReport::new(
+                year.year,
+                day,
+                input_name.clone(),
+                &stats,
+                solution_with_io,
+                timings,
+                &result,
+                &check,
+            )
+            .with_allocs(ALLOC_STATS.then_some(allocs)),
+        );
+        times.push(t);
+        times_io.push(solution_with_io);
+        if let Check::Failed(_) = check {
+            failed_days.push(day);
+        }
+        let delta = baseline.compare(reports.last().unwrap(), opt.regression_threshold);
+        if delta.is_some_and(|d| d.regression) {
+            regressed_days.push(day);
+        }
+        if !text {
+            return;
+        }
+        println!(
+            "Day {:02} took {:>9} to compute (rsum {:>9}) (with i/o: {:>9}, rsum {:>9})",
+            day,
+            d2s(t),
+            d2s(running_sum_compute),
+            d2s(solution_with_io),
+            d2s(running_sum_io)
+        );
+        println!(
+            "\tread {:>9}, parse {:>9}, part 1 {:>9}, part 2 {:>9}{}",
+            d2s(timings.read),
+            d2s(timings.parse),
+            d2s(timings.part1),
+            d2s(timings.part2),
+            if year.days[day - 1].parallel_parts() {
+                " (parts in parallel)"
+            } else {
+                ""
+            }
+        );
+        if ALLOC_STATS {
             println!(
-                "Day {:02} took {:>9} to compute (rsum {:>9}) (with i/o: {:>9}, rsum {:>9})",
-                i + 1,
-                d2s(t),
-                d2s(running_sum_compute),
-                d2s(solution_with_io),
-                d2s(running_sum_io)
+                "\tallocations {:>9}, allocated {:>9}, peak {:>9}",
+                allocs.allocations,
+                bytes(allocs.bytes),
+                bytes(allocs.peak),
             );
-            times.push(t);
-            times_io.push(solution_with_io);
         }
-    }
+        if let Some(delta) = delta {
+            println!("\t{delta}");
+        }
+        if stats.runs > 1 {
+            println!(
+                "\t{} runs: min {:>9}, med {:>9}, mean {:>9}, stddev {:>9}, p95 {:>9}",
+                stats.runs,
+                d2s(stats.min),
+                d2s(stats.median),
+                d2s(stats.mean),
+                d2s(stats.stddev),
+                d2s(stats.p95),
+            );
+        }
+        if let Check::Failed(mismatches) = check {
+            println!("Day {:02} gave wrong answers for {input_name}:", day);
+            for mismatch in mismatches {
+                println!("{mismatch}");
+            }
+        }
+    };
+
+    let timeout = opt.timeout.map(Duration::from_secs_f64);
+    let (part, warmup, runs) = (opt.part, opt.warmup, opt.runs);
+    let wall_clock = if opt.parallel {
+        if text {
+            println!(
+                "Running {} days in parallel, per-day times are measured under contention.",
+                inputs.len()
+            );
+            if ALLOC_STATS {
+                println!("Allocations of days running at the same time are counted together.");
+            }
+        }
+        let start = Instant::now();
+        let inputs = inputs
+            .into_iter()
+            .map(|(day, input)| {
+                let params = answers.params(&input.name());
+                (day, input, params)
+            })
+            .collect();
+        let outcomes = run_parallel(year, inputs, part, warmup, runs, timeout);
+        let wall_clock = start.elapsed();
+        for (day, input_name, outcome) in outcomes {
+            report_day(day, input_name, outcome);
+        }
+        Some(wall_clock)
+    } else {
+        for (day, input) in inputs {
+            let input_name = input.name();
+            let params = answers.params(&input_name);
+            let solution = year.days[day - 1];
+            let outcome = isolated(timeout, move || {
+                measure(solution, &input, &params, part, warmup, runs)
+            });
+            report_day(day, input_name, outcome);
+        }
+        None
+    };
 
     times.sort();
     times_io.sort();
@@ -134,24 +728,76 @@ fn main() {
     let total_io = times_io.iter().sum();
     let min_io = times_io.iter().min();
     let max_io = times_io.iter().max();
-    if opt.day_to_run.is_none() {
+    if !text {
+        report::write(opt.format, &reports, std::io::stdout().lock()).unwrap_or_else(|e| {
+            eprintln!("Failed to write report: {e:#}");
+            std::process::exit(1)
+        });
+    } else if times.len() > 1 {
         println!(
             "\n         Total time for {} days: {:>9} (avg per day {:>9}, med: {:>9}, min: {:>9}, max: {:>9})",
-            solutions.len(),
+            times.len(),
             d2s(total),
-            d2s(total.div_f64(solutions.len() as f64)),
+            d2s(total.div_f64(times.len() as f64)),
             d2s(median(&times)),
             d2s(*min.unwrap()),
             d2s(*max.unwrap()),
         );
         println!(
             "Total time with i/o for {} days: {:>9} (avg per day {:>9}, med: {:>9}, min: {:>9}, max: {:>9})",
-            solutions.len(),
+            times.len(),
             d2s(total_io),
-            d2s(total_io.div_f64(solutions.len() as f64)),
+            d2s(total_io.div_f64(times.len() as f64)),
             d2s(median(&times_io)),
             d2s(*min_io.unwrap()),
             d2s(*max_io.unwrap()),
         );
+        if let Some(wall_clock) = wall_clock {
+            println!(
+                "    Wall-clock time for {} days: {:>9} (in parallel, vs {:>9} sum of per-day compute times)",
+                times.len(),
+                d2s(wall_clock),
+                d2s(total),
+            );
+        }
+    }
+
+    if let Some(path) = &opt.save_baseline {
+        Baseline::save(path, &reports).unwrap_or_else(|e| {
+            eprintln!("{e:#}");
+            std::process::exit(1)
+        });
+    }
+
+    if !regressed_days.is_empty() {
+        eprintln!(
+            "\nCompute time regressed by more than {}% for {} days: {}",
+            opt.regression_threshold,
+            regressed_days.len(),
+            regressed_days.iter().map(|d| format!("{d:02}")).join(", ")
+        );
+    }
+    if !unfinished_days.is_empty() {
+        eprintln!(
+            "\n{} days did not finish: {}",
+            unfinished_days.len(),
+            unfinished_days
+                .iter()
+                .map(|(d, status)| format!("{d:02} ({status})"))
+                .join(", ")
+        );
+    }
+    if !failed_days.is_empty() {
+        eprintln!(
+            "\nVerification failed for {} days: {}",
+            failed_days.len(),
+            failed_days.iter().map(|d| format!("{d:02}")).join(", ")
+        );
+    }
+    if !failed_days.is_empty() || !unfinished_days.is_empty() {
+        std::process::exit(1);
+    }
+    if opt.fail_on_regression && !regressed_days.is_empty() {
+        std::process::exit(1);
     }
 }
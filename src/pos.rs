@@ -1,33 +1,35 @@
+use anyhow::{bail, Result};
 use std::fmt::Debug;
 use std::hash::Hash;
-use std::ops::{Add, Mul, Sub};
+use std::ops::{Add, Div, Mul, Sub};
+use std::str::FromStr;
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub(crate) struct Pos<T: Debug + Clone + Copy + PartialEq + Eq + PartialOrd + Ord + Hash> {
+pub struct Pos<T> {
     pub(crate) x: T,
     pub(crate) y: T,
 }
 
-impl<T> Debug for Pos<T>
-where
-    T: Debug
-        + Clone
-        + Copy
-        + PartialEq
-        + Eq
-        + PartialOrd
-        + Ord
-        + Hash
-        + Sub
-        + num::Signed
-        + TryInto<usize>,
-    <T as TryInto<usize>>::Error: Debug,
-{
+impl<T: Debug> Debug for Pos<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "({:?}, {:?})", self.x, self.y)
     }
 }
 
+impl<T> Pos<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Copy + num::Num> Pos<T> {
+    /// Cross product of both as vectors on the `xy` plane, which is the `z`
+    /// of their cross product in 3D.
+    pub fn cross(self, other: Self) -> T {
+        self.x * other.y - self.y * other.x
+    }
+}
+
 impl<T> Pos<T>
 where
     T: Debug
@@ -43,10 +45,6 @@ where
         + TryInto<usize>,
     <T as TryInto<usize>>::Error: Debug,
 {
-    pub const fn new(x: T, y: T) -> Self {
-        Self { x, y }
-    }
-
     #[inline(always)]
     pub fn manhattan_dist(&self, other: &Self) -> T {
         (self.x - other.x).abs() + (self.y - other.y).abs()
@@ -68,21 +66,7 @@ where
     }
 }
 
-impl<T> Mul<T> for Pos<T>
-where
-    T: Debug
-        + Clone
-        + Copy
-        + PartialEq
-        + Eq
-        + PartialOrd
-        + Ord
-        + Hash
-        + Sub
-        + num::Signed
-        + TryInto<usize>,
-    <T as TryInto<usize>>::Error: Debug,
-{
+impl<T: Copy + num::Num> Mul<T> for Pos<T> {
     type Output = Self;
 
     fn mul(self, rhs: T) -> Self::Output {
@@ -95,31 +79,8 @@ where
 
 impl<T, U> Add<Pos<U>> for Pos<T>
 where
-    T: Debug
-        + Clone
-        + Copy
-        + PartialEq
-        + Eq
-        + PartialOrd
-        + Ord
-        + Hash
-        + Sub
-        + num::Signed
-        + From<U>
-        + TryInto<usize>,
-    <T as TryInto<usize>>::Error: Debug,
-    U: Debug
-        + Clone
-        + Copy
-        + PartialEq
-        + Eq
-        + PartialOrd
-        + Ord
-        + Hash
-        + Sub
-        + num::Signed
-        + TryInto<usize>,
-    <U as TryInto<usize>>::Error: Debug,
+    T: Copy + num::Num + From<U>,
+    U: Copy,
 {
     type Output = Self;
 
@@ -131,24 +92,412 @@ where
     }
 }
 
-impl<T> Sub for Pos<T>
-where
-    T: Debug
-        + Clone
+impl<T: Copy + num::Num> Sub for Pos<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+/// Position or vector in 3D space.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pos3<T> {
+    pub(crate) x: T,
+    pub(crate) y: T,
+    pub(crate) z: T,
+}
+
+impl<T: Debug> Debug for Pos3<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({:?}, {:?}, {:?})", self.x, self.y, self.z)
+    }
+}
+
+impl<T: Copy> Pos3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn map<U>(self, f: impl Fn(T) -> U) -> Pos3<U> {
+        Pos3 {
+            x: f(self.x),
+            y: f(self.y),
+            z: f(self.z),
+        }
+    }
+
+    /// Projection on the `xy` plane.
+    pub fn xy(self) -> Pos<T> {
+        Pos::new(self.x, self.y)
+    }
+}
+
+impl<T: Copy + num::Num> Pos3<T> {
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn cross(self, other: Self) -> Self {
+        Self::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    /// Component-wise minimum.
+    pub fn min_each(self, other: Self) -> Self
+    where
+        T: PartialOrd,
+    {
+        let min = |a: T, b: T| if b < a { b } else { a };
+        Self::new(
+            min(self.x, other.x),
+            min(self.y, other.y),
+            min(self.z, other.z),
+        )
+    }
+}
+
+impl<T: Copy + num::Signed> Pos3<T> {
+    pub fn manhattan_dist(&self, other: &Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+}
+
+impl<T: Copy + num::Num + From<i8>> Pos3<T> {
+    /// Positions sharing a face with this one.
+    pub fn neighbours6(self) -> impl Iterator<Item = Self> {
+        [
+            (1, 0, 0),
+            (-1, 0, 0),
+            (0, 1, 0),
+            (0, -1, 0),
+            (0, 0, 1),
+            (0, 0, -1),
+        ]
+        .into_iter()
+        .map(move |(x, y, z)| self + Pos3::new(x, y, z).map(T::from))
+    }
+
+    /// Positions sharing a face, an edge or a corner with this one.
+    pub fn neighbours26(self) -> impl Iterator<Item = Self> {
+        Pos3::cuboid(Pos3::new(-1, -1, -1), Pos3::new(1, 1, 1))
+            .filter(|d: &Pos3<i8>| *d != Pos3::default())
+            .map(move |d| self + d.map(T::from))
+    }
+}
+
+impl<T: Copy + num::Integer + num::ToPrimitive> Pos3<T> {
+    /// Positions of the axis-aligned box between `min` and `max`, inclusive.
+    pub fn cuboid(min: Self, max: Self) -> impl Iterator<Item = Self> {
+        num::range_inclusive(min.x, max.x).flat_map(move |x| {
+            num::range_inclusive(min.y, max.y).flat_map(move |y| {
+                num::range_inclusive(min.z, max.z).map(move |z| Self::new(x, y, z))
+            })
+        })
+    }
+}
+
+impl<T: Copy + num::Num> Add for Pos3<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T: Copy + num::Num> Sub for Pos3<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T: Copy + num::Num> Mul<T> for Pos3<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        self.map(|v| v * rhs)
+    }
+}
+
+impl<T: Copy + num::Num> Div<T> for Pos3<T> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        self.map(|v| v / rhs)
+    }
+}
+
+/// Integer type of coordinates of positions in a [`Grid`](crate::vec::Grid).
+pub trait Coord:
+    'static + Debug + Copy + Ord + Hash + num::Signed + From<i8> + TryInto<usize> + TryFrom<usize>
+{
+}
+
+impl<T> Coord for T where
+    T: 'static
+        + Debug
         + Copy
-        + PartialEq
-        + Eq
-        + PartialOrd
         + Ord
         + Hash
-        + Sub
         + num::Signed
-        + TryInto<usize>,
-    <T as TryInto<usize>>::Error: Debug,
+        + From<i8>
+        + TryInto<usize>
+        + TryFrom<usize>
 {
-    type Output = Self;
+}
 
-    fn sub(self, rhs: Self) -> Self::Output {
-        Self::new(self.x - rhs.x, self.y - rhs.y)
+/// One of four directions on a grid where `y` grows downwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    /// All directions, clockwise from `Up`.
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    pub const fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub const fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub const fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    pub const fn is_horizontal(self) -> bool {
+        matches!(self, Dir4::Left | Dir4::Right)
+    }
+
+    /// Change of position after a step in this direction.
+    pub const fn delta(self) -> Pos<i8> {
+        let (x, y) = match self {
+            Dir4::Up => (0, -1),
+            Dir4::Right => (1, 0),
+            Dir4::Down => (0, 1),
+            Dir4::Left => (-1, 0),
+        };
+        Pos { x, y }
+    }
+}
+
+impl From<Dir4> for Pos<i8> {
+    fn from(dir: Dir4) -> Self {
+        dir.delta()
+    }
+}
+
+impl TryFrom<Pos<i8>> for Dir4 {
+    type Error = anyhow::Error;
+
+    fn try_from(delta: Pos<i8>) -> Result<Self> {
+        match Dir4::ALL.into_iter().find(|d| d.delta() == delta) {
+            Some(dir) => Ok(dir),
+            None => bail!(
+                "({}, {}) is not a step in one of four directions",
+                delta.x,
+                delta.y
+            ),
+        }
+    }
+}
+
+/// Parses `U`/`R`/`D`/`L`, `N`/`E`/`S`/`W` or an arrow, `^`/`>`/`v`/`<`.
+impl TryFrom<char> for Dir4 {
+    type Error = anyhow::Error;
+
+    fn try_from(c: char) -> Result<Self> {
+        Ok(match c {
+            'U' | 'N' | '^' => Dir4::Up,
+            'R' | 'E' | '>' => Dir4::Right,
+            'D' | 'S' | 'v' => Dir4::Down,
+            'L' | 'W' | '<' => Dir4::Left,
+            c => bail!("'{c}' is not a direction"),
+        })
+    }
+}
+
+impl FromStr for Dir4 {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => c.try_into(),
+            _ => bail!("'{s}' is not a direction"),
+        }
+    }
+}
+
+/// One of eight directions on a grid where `y` grows downwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir8 {
+    /// All directions, clockwise from `Up`.
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Up,
+        Dir8::UpRight,
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+    ];
+
+    /// Turns clockwise by an eighth of a full turn.
+    pub const fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turns counterclockwise by an eighth of a full turn.
+    pub const fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub const fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    /// Change of position after a step in this direction.
+    pub const fn delta(self) -> Pos<i8> {
+        let (x, y) = match self {
+            Dir8::Up => (0, -1),
+            Dir8::UpRight => (1, -1),
+            Dir8::Right => (1, 0),
+            Dir8::DownRight => (1, 1),
+            Dir8::Down => (0, 1),
+            Dir8::DownLeft => (-1, 1),
+            Dir8::Left => (-1, 0),
+            Dir8::UpLeft => (-1, -1),
+        };
+        Pos { x, y }
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Self::ALL[dir as usize * 2]
+    }
+}
+
+impl From<Dir8> for Pos<i8> {
+    fn from(dir: Dir8) -> Self {
+        dir.delta()
+    }
+}
+
+impl TryFrom<Pos<i8>> for Dir8 {
+    type Error = anyhow::Error;
+
+    fn try_from(delta: Pos<i8>) -> Result<Self> {
+        match Dir8::ALL.into_iter().find(|d| d.delta() == delta) {
+            Some(dir) => Ok(dir),
+            None => bail!(
+                "({}, {}) is not a step in one of eight directions",
+                delta.x,
+                delta.y
+            ),
+        }
+    }
+}
+
+/// Parses compass points, like `N` or `SW`, and everything [`Dir4`] parses.
+impl FromStr for Dir8 {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match s {
+            "NE" => Dir8::UpRight,
+            "SE" => Dir8::DownRight,
+            "SW" => Dir8::DownLeft,
+            "NW" => Dir8::UpLeft,
+            s => s.parse::<Dir4>()?.into(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn directions_turn_and_convert() {
+        for dir in Dir4::ALL {
+            assert_eq!(dir, dir.turn_left().turn_right());
+            assert_eq!(dir.opposite(), dir.turn_right().turn_right());
+            assert_eq!(dir, Dir4::try_from(dir.delta()).unwrap());
+            assert_eq!(Dir8::from(dir).delta(), dir.delta());
+        }
+        for dir in Dir8::ALL {
+            assert_eq!(dir.opposite(), (0..4).fold(dir, |d, _| d.turn_left()));
+            assert_eq!(dir.delta() * -1, dir.opposite().delta());
+            assert_eq!(dir, Dir8::try_from(dir.delta()).unwrap());
+        }
+        assert_eq!(Dir4::Left, Dir4::Up.turn_left());
+        assert_eq!(
+            vec![Dir4::Up, Dir4::Up, Dir4::Up, Dir4::Left, Dir4::Right],
+            ["U", "N", "^", "<", "E"]
+                .iter()
+                .map(|s| s.parse().unwrap())
+                .collect::<Vec<Dir4>>()
+        );
+        assert!("UR".parse::<Dir4>().is_err());
+        assert_eq!(Dir8::DownLeft, "SW".parse().unwrap());
+        assert_eq!(Dir8::Down, "v".parse().unwrap());
+        assert!(Dir4::try_from(Pos { x: 1, y: 1 }).is_err());
+    }
+
+    #[test]
+    fn pos3_arithmetic_and_neighbours() {
+        let a = Pos3::new(1i64, 2, 3);
+        let b = Pos3::new(4i64, -5, 6);
+        assert_eq!(Pos3::new(5, -3, 9), a + b);
+        assert_eq!(Pos3::new(2, 4, 6), (a * 4) / 2);
+        assert_eq!(12, a.dot(b));
+        assert_eq!(0, a.cross(b).dot(a));
+        assert_eq!(
+            Pos3::new(0, 0, 1),
+            Pos3::new(1, 0, 0).cross(Pos3::new(0, 1, 0))
+        );
+        assert_eq!(13, a.manhattan_dist(&b));
+        assert_eq!(Pos::new(1, 2), a.xy());
+        assert_eq!(Pos3::new(1, -5, 3), a.min_each(b));
+        assert_eq!(
+            6,
+            a.neighbours6()
+                .filter(|n| n.manhattan_dist(&a) == 1)
+                .count()
+        );
+        assert_eq!(
+            26,
+            a.neighbours26()
+                .collect::<std::collections::HashSet<_>>()
+                .len()
+        );
+        assert_eq!(12, Pos3::cuboid(a, Pos3::new(2, 3, 5)).count());
+        assert_eq!(Pos::new(2.0, 0.5), Pos3::new(2.0, 0.5, 7.0).xy());
+        assert_eq!(a.cross(b).z, a.xy().cross(b.xy()));
     }
 }
@@ -0,0 +1,270 @@
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::io::Write;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::alloc::AllocStats;
+use crate::answers::Check;
+use crate::solution::Answer;
+use crate::stats::Stats;
+
+/// Output format of the runner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Human readable lines, printed as days finish.
+    Text,
+    /// JSON array with one object per day.
+    Json,
+    /// CSV with a header and one row per day.
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match s {
+            "text" => Format::Text,
+            "json" => Format::Json,
+            "csv" => Format::Csv,
+            _ => bail!("unknown format '{s}', expected one of: text, json, csv"),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Ok,
+    Failed,
+    /// No expected answers are known, or verification was skipped.
+    Unverified,
+    /// Day did not finish, because of an error like missing input.
+    Error,
+    Panicked,
+    TimedOut,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Status::Ok => "ok",
+            Status::Failed => "failed",
+            Status::Unverified => "unverified",
+            Status::Error => "error",
+            Status::Panicked => "panicked",
+            Status::TimedOut => "timed out",
+        };
+        write!(f, "{s}")
+    }
+}
+
+impl From<&Check> for Status {
+    fn from(check: &Check) -> Self {
+        match check {
+            Check::Ok => Status::Ok,
+            Check::Missing => Status::Unverified,
+            Check::Failed(_) => Status::Failed,
+        }
+    }
+}
+
+/// Time spent in consecutive phases of a single run of a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    /// Mapping the input file and validating it as UTF-8.
+    pub read: Duration,
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+    /// Both parts together. Less than their sum for days running parts in parallel.
+    pub solve: Duration,
+}
+
+/// Results of a single day, as emitted by the machine readable formats.
+///
+/// `compute_ns` and `with_io_ns` are the fastest of all runs, the statistics
+/// describe compute time only. Split of time between phases comes from the
+/// run with the fastest compute time.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayReport {
+    /// Reports saved before there were other years are of 2023.
+    #[serde(default = "first_year")]
+    pub year: u16,
+    pub day: usize,
+    pub input: String,
+    pub compute_ns: u64,
+    pub with_io_ns: u64,
+    pub runs: usize,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+    pub stddev_ns: u64,
+    pub p95_ns: u64,
+    #[serde(default)]
+    pub read_ns: u64,
+    #[serde(default)]
+    pub parse_ns: u64,
+    #[serde(default)]
+    pub part1_ns: u64,
+    #[serde(default)]
+    pub part2_ns: u64,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub status: Status,
+    /// Why the day did not finish.
+    #[serde(default)]
+    pub error: Option<String>,
+    /// Allocations of a single run, reported with the `alloc-stats` feature.
+    #[serde(default)]
+    pub allocations: Option<u64>,
+    #[serde(default)]
+    pub allocated_bytes: Option<u64>,
+    #[serde(default)]
+    pub peak_bytes: Option<u64>,
+}
+
+fn first_year() -> u16 {
+    2023
+}
+
+impl DayReport {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        year: u16,
+        day: usize,
+        input: String,
+        compute: &Stats,
+        with_io: Duration,
+        timings: &Timings,
+        answers: &[Answer; 2],
+        check: &Check,
+    ) -> Self {
+        let answer = |a: &Answer| (*a != Answer::None).then(|| a.to_string());
+        Self {
+            year,
+            day,
+            input,
+            compute_ns: nanos(compute.min),
+            with_io_ns: nanos(with_io),
+            runs: compute.runs,
+            median_ns: nanos(compute.median),
+            mean_ns: nanos(compute.mean),
+            stddev_ns: nanos(compute.stddev),
+            p95_ns: nanos(compute.p95),
+            read_ns: nanos(timings.read),
+            parse_ns: nanos(timings.parse),
+            part1_ns: nanos(timings.part1),
+            part2_ns: nanos(timings.part2),
+            part1: answer(&answers[0]),
+            part2: answer(&answers[1]),
+            status: check.into(),
+            error: None,
+            allocations: None,
+            allocated_bytes: None,
+            peak_bytes: None,
+        }
+    }
+
+    pub fn with_allocs(self, allocs: Option<AllocStats>) -> Self {
+        Self {
+            allocations: allocs.map(|a| a.allocations),
+            allocated_bytes: allocs.map(|a| a.bytes),
+            peak_bytes: allocs.map(|a| a.peak),
+            ..self
+        }
+    }
+
+    /// The day finished with answers that are not known to be wrong, so its
+    /// timings can serve as a baseline.
+    pub fn is_timed(&self) -> bool {
+        self.runs > 0 && matches!(self.status, Status::Ok | Status::Unverified)
+    }
+
+    /// Report of a day that did not finish, without timings nor answers.
+    pub fn unfinished(year: u16, day: usize, input: String, status: Status, error: String) -> Self {
+        Self {
+            year,
+            day,
+            input,
+            compute_ns: 0,
+            with_io_ns: 0,
+            runs: 0,
+            median_ns: 0,
+            mean_ns: 0,
+            stddev_ns: 0,
+            p95_ns: 0,
+            read_ns: 0,
+            parse_ns: 0,
+            part1_ns: 0,
+            part2_ns: 0,
+            part1: None,
+            part2: None,
+            status,
+            error: Some(error),
+            allocations: None,
+            allocated_bytes: None,
+            peak_bytes: None,
+        }
+    }
+}
+
+fn nanos(d: Duration) -> u64 {
+    d.as_nanos() as u64
+}
+
+/// Writes `reports` in a machine readable `format`. Does nothing for [`Format::Text`].
+pub fn write(format: Format, reports: &[DayReport], out: impl Write) -> Result<()> {
+    match format {
+        Format::Text => {}
+        Format::Json => {
+            let mut out = out;
+            serde_json::to_writer_pretty(&mut out, reports)?;
+            writeln!(out)?;
+        }
+        Format::Csv => {
+            let mut writer = csv::Writer::from_writer(out);
+            for report in reports {
+                writer.serialize(report)?;
+            }
+            writer.flush()?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_csv_with_header() {
+        let report = DayReport::new(
+            2023,
+            25,
+            "day25".to_owned(),
+            &Stats::new(&[Duration::from_micros(3), Duration::from_micros(4)]).unwrap(),
+            Duration::from_micros(5),
+            &Timings {
+                read: Duration::from_nanos(100),
+                parse: Duration::from_nanos(200),
+                part1: Duration::from_nanos(1000),
+                part2: Duration::from_nanos(2000),
+                solve: Duration::from_micros(3),
+            },
+            &[Answer::Num(54), Answer::None],
+            &Check::Ok,
+        );
+        let mut out = vec![];
+        write(Format::Csv, &[report], &mut out).unwrap();
+
+        assert_eq!(
+            "year,day,input,compute_ns,with_io_ns,runs,median_ns,mean_ns,stddev_ns,p95_ns,\
+             read_ns,parse_ns,part1_ns,part2_ns,part1,part2,status,error,\
+             allocations,allocated_bytes,peak_bytes\n\
+             2023,25,day25,3000,5000,2,3500,3500,500,4000,100,200,1000,2000,54,,ok,,,,\n",
+            String::from_utf8(out).unwrap()
+        );
+    }
+}
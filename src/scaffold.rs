@@ -0,0 +1,389 @@
+use anyhow::{bail, ensure, Context, Result};
+use regex::Regex;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+fn template(day: usize) -> String {
+    format!(
+        r#"use anyhow::Result;
+
+use crate::solution::{{Answer, Solution}};
+
+pub struct Day{day:02};
+
+impl Solution for Day{day:02} {{
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {{
+        Ok(input.lines().map(|l| l.to_owned()).collect())
+    }}
+
+    fn part1(_input: &Self::Input) -> Answer {{
+        Answer::None
+    }}
+
+    fn part2(_input: &Self::Input) -> Answer {{
+        Answer::None
+    }}
+}}
+"#
+    )
+}
+
+const ANSWERS_HEADER: &str =
+    "# Expected answers: input file name followed by answers for consecutive parts.";
+
+// Source of the module of a new `year`, with its first day.
+fn year_template(year: u16) -> String {
+    format!(
+        r#"pub mod day01;
+
+use crate::solution::DynSolution;
+
+/// All days of the {year} calendar, in order.
+pub const SOLUTIONS: &[&dyn DynSolution] = &[&day01::Day01];
+"#
+    )
+}
+
+// Byte range of the elements of the array `name` is set to in `source`,
+// between `&[` and `];`.
+fn array(source: &str, name: &str) -> Result<Range<usize>> {
+    let decl = source
+        .find(&format!("pub const {name}:"))
+        .with_context(|| format!("{name} is not defined"))?;
+    let not_array = || format!("{name} is not an array");
+    let start = decl + source[decl..].find("= &[").with_context(not_array)? + 4;
+    let end = start + source[start..].find("];").with_context(not_array)?;
+    Ok(start..end)
+}
+
+// Inserts `line` after the last line of `source` starting with `after`.
+fn insert_line(source: &str, after: &str, line: &str) -> Result<String> {
+    let mut lines: Vec<&str> = source.lines().collect();
+    let at = lines
+        .iter()
+        .rposition(|l| l.starts_with(after))
+        .with_context(|| format!("no line starts with '{after}'"))?;
+    lines.insert(at + 1, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+// Adds the module of `day` and its entry in `SOLUTIONS` to the source of the
+// module of its year. Entries are written one per line, and read both so and
+// in a single line, as rustfmt leaves short arrays.
+fn register(year_rs: &str, day: usize) -> Result<String> {
+    let prev_mod = format!("pub mod day{:02};", day - 1);
+    let prev_entry = format!("&day{0:02}::Day{0:02}", day - 1);
+    if !year_rs.lines().any(|l| l == prev_mod) {
+        bail!(
+            "days must be added in order, but day {} is missing",
+            day - 1
+        );
+    }
+    if year_rs
+        .lines()
+        .any(|l| l == format!("pub mod day{day:02};"))
+    {
+        bail!("day {day} is already registered");
+    }
+    let range = array(year_rs, "SOLUTIONS")?;
+    let mut entries: Vec<&str> = year_rs[range.clone()]
+        .split(',')
+        .map(str::trim)
+        .filter(|e| !e.is_empty())
+        .collect();
+    if !entries.contains(&prev_entry.as_str()) {
+        bail!("day {} is not in SOLUTIONS", day - 1);
+    }
+    let entry = format!("&day{day:02}::Day{day:02}");
+    entries.push(&entry);
+    let entries: String = entries.iter().map(|e| format!("    {e},\n")).collect();
+    let year_rs = format!(
+        "{}\n{entries}{}",
+        &year_rs[..range.start],
+        &year_rs[range.end..]
+    );
+    insert_line(&year_rs, &prev_mod, &format!("pub mod day{day:02};"))
+}
+
+// Adds the module of `year` and its entry in `YEARS` to the source of the
+// crate root.
+fn register_year(lib_rs: &str, year: u16) -> Result<String> {
+    if lib_rs.lines().any(|l| l == format!("pub mod year{year};")) {
+        bail!("year {year} is already registered");
+    }
+    let range = array(lib_rs, "YEARS")?;
+    let mut years = Regex::new(r"year: (\d+)")?
+        .captures_iter(&lib_rs[range.clone()])
+        .map(|c| Ok(c[1].parse()?))
+        .collect::<Result<Vec<u16>>>()?;
+    years.push(year);
+    years.sort();
+    let entries: String = years
+        .iter()
+        .map(|y| {
+            format!("    Year {{\n        year: {y},\n        days: year{y}::SOLUTIONS,\n    }},\n")
+        })
+        .collect();
+    let lib_rs = format!(
+        "{}\n{entries}{}",
+        &lib_rs[..range.start],
+        &lib_rs[range.end..]
+    );
+    insert_line(&lib_rs, "pub mod year", &format!("pub mod year{year};"))
+}
+
+fn create(path: &Path, contents: &str) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create {}", dir.display()))?;
+    }
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .and_then(|mut f| f.write_all(contents.as_bytes()))
+        .with_context(|| format!("Failed to create {}", path.display()))
+}
+
+fn read(path: &Path) -> Result<String> {
+    std::fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))
+}
+
+// Change of a single file made by `new_day`.
+enum Change {
+    Create(PathBuf, String),
+    /// Path, its old contents and its new ones.
+    Replace(PathBuf, String, String),
+}
+
+impl Change {
+    fn path(&self) -> &Path {
+        match self {
+            Change::Create(path, _) | Change::Replace(path, _, _) => path,
+        }
+    }
+
+    fn apply(&self) -> Result<()> {
+        match self {
+            Change::Create(path, contents) => create(path, contents),
+            Change::Replace(path, _, contents) => std::fs::write(path, contents)
+                .with_context(|| format!("Failed to write {}", path.display())),
+        }
+    }
+
+    // Best effort, as it runs after another change has already failed.
+    fn undo(&self) {
+        let _ = match self {
+            Change::Create(path, _) => std::fs::remove_file(path),
+            Change::Replace(path, old, _) => std::fs::write(path, old),
+        };
+    }
+}
+
+// Changes adding `day` of `year`, checked to apply to the files as they are.
+fn plan(root: &Path, year: u16, day: usize) -> Result<Vec<Change>> {
+    ensure!((1..=25).contains(&day), "day {day} is not in 1-25");
+    let year_path = root.join(format!("src/year{year}/mod.rs"));
+    let answers = root.join(format!("inputs/{year}/answers"));
+    let example_answers = format!("day{day:02}_test - -\n");
+
+    let mut changes = vec![];
+    if year_path.exists() {
+        let year_rs = read(&year_path)?;
+        let new_year_rs = register(&year_rs, day)?;
+        changes.push(Change::Replace(year_path, year_rs, new_year_rs));
+        let old = read(&answers)?;
+        let mut new = old.clone();
+        if !new.is_empty() && !new.ends_with('\n') {
+            new.push('\n');
+        }
+        new += &example_answers;
+        changes.push(Change::Replace(answers, old, new));
+    } else {
+        ensure!(
+            day == 1,
+            "year {year} has no module yet, so its first day is 1, not {day}"
+        );
+        let lib_path = root.join("src/lib.rs");
+        let lib_rs = read(&lib_path)?;
+        let new_lib_rs = register_year(&lib_rs, year)?;
+        changes.push(Change::Replace(lib_path, lib_rs, new_lib_rs));
+        changes.push(Change::Create(year_path, year_template(year)));
+        changes.push(Change::Create(
+            answers,
+            format!("{ANSWERS_HEADER}\n{example_answers}"),
+        ));
+    }
+    for (name, contents) in [
+        (format!("src/year{year}/day{day:02}.rs"), template(day)),
+        (format!("inputs/{year}/day{day:02}"), String::new()),
+        (format!("inputs/{year}/day{day:02}_test"), String::new()),
+    ] {
+        changes.push(Change::Create(root.join(name), contents));
+    }
+
+    for change in &changes {
+        if let Change::Create(path, _) = change {
+            ensure!(!path.exists(), "{} already exists", path.display());
+        }
+    }
+    Ok(changes)
+}
+
+/// Creates the module of a new `day` of `year` in the crate at `root`,
+/// registers it in `SOLUTIONS` of the year and adds placeholders for its input
+/// and example input. Day 1 of a year without a module also creates the module
+/// of the year and registers it in `YEARS`. Nothing is changed when any file
+/// cannot be, and the created and modified files are returned otherwise.
+pub fn new_day(root: &Path, year: u16, day: usize) -> Result<Vec<PathBuf>> {
+    let changes = plan(root, year, day)?;
+    for (i, change) in changes.iter().enumerate() {
+        if let Err(e) = change.apply() {
+            changes[..i].iter().rev().for_each(Change::undo);
+            return Err(e);
+        }
+    }
+    Ok(changes.iter().map(|c| c.path().to_owned()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::stub::serve_once;
+    use crate::client::{fetch_input, Client, UreqHttp};
+
+    const YEAR_RS: &str = "pub mod day01;
+pub mod day02;
+
+pub const SOLUTIONS: &[&dyn DynSolution] = &[
+    &day01::Day01,
+    &day02::Day02,
+];
+";
+
+    #[test]
+    fn registers_next_day() {
+        assert_eq!(
+            "pub mod day01;
+pub mod day02;
+pub mod day03;
+
+pub const SOLUTIONS: &[&dyn DynSolution] = &[
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+];
+",
+            register(YEAR_RS, 3).unwrap()
+        );
+        // As rustfmt leaves a short array.
+        let year_rs = register(&year_template(2024), 2).unwrap();
+        assert!(year_rs.starts_with("pub mod day01;\npub mod day02;\n"));
+        assert!(year_rs.ends_with("= &[\n    &day01::Day01,\n    &day02::Day02,\n];\n"));
+        assert!(register(YEAR_RS, 2).is_err());
+        assert!(register(YEAR_RS, 5).is_err());
+    }
+
+    const LIB_RS: &str = "pub mod solution;
+pub mod year2023;
+
+pub const YEARS: &[Year] = &[Year {
+    year: 2023,
+    days: year2023::SOLUTIONS,
+}];
+";
+
+    // Crate with the source of 2023 with days 1 and 2 and `files`.
+    fn crate_root(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("aoc23-{name}-{}", std::process::id()));
+        for (path, contents) in [
+            ("src/lib.rs", LIB_RS),
+            ("src/year2023/mod.rs", YEAR_RS),
+            ("inputs/2023/answers", ""),
+        ]
+        .iter()
+        .chain(files)
+        {
+            std::fs::create_dir_all(root.join(path).parent().unwrap()).unwrap();
+            std::fs::write(root.join(path), contents).unwrap();
+        }
+        root
+    }
+
+    #[test]
+    fn starts_new_year_with_day_1() {
+        let root = crate_root("new-year", &[]);
+        assert!(new_day(&root, 2024, 2).is_err());
+        new_day(&root, 2024, 1).unwrap();
+        new_day(&root, 2024, 2).unwrap();
+
+        let lib_rs = std::fs::read_to_string(root.join("src/lib.rs")).unwrap();
+        assert_eq!(
+            "pub mod solution;
+pub mod year2023;
+pub mod year2024;
+
+pub const YEARS: &[Year] = &[
+    Year {
+        year: 2023,
+        days: year2023::SOLUTIONS,
+    },
+    Year {
+        year: 2024,
+        days: year2024::SOLUTIONS,
+    },
+];
+",
+            lib_rs
+        );
+        let year_rs = std::fs::read_to_string(root.join("src/year2024/mod.rs")).unwrap();
+        assert!(year_rs.contains("pub mod day02;\n"));
+        assert!(year_rs.contains("    &day01::Day01,\n    &day02::Day02,\n];"));
+        let answers = std::fs::read_to_string(root.join("inputs/2024/answers")).unwrap();
+        assert!(answers.ends_with("day01_test - -\nday02_test - -\n"));
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn changes_nothing_on_failure() {
+        // Example left over from an earlier attempt.
+        let root = crate_root("stray", &[("inputs/2023/day03_test", "")]);
+        assert!(new_day(&root, 2023, 3).is_err());
+        assert_eq!(
+            YEAR_RS,
+            std::fs::read_to_string(root.join("src/year2023/mod.rs")).unwrap()
+        );
+        assert!(!root.join("src/year2023/day03.rs").exists());
+        std::fs::remove_dir_all(root).unwrap();
+
+        // Answers of the new year cannot be written, after the crate root and
+        // the module of the year were.
+        let root = crate_root("unwritable", &[("inputs/2024", "")]);
+        assert!(new_day(&root, 2024, 1).is_err());
+        assert_eq!(
+            LIB_RS,
+            std::fs::read_to_string(root.join("src/lib.rs")).unwrap()
+        );
+        assert!(!root.join("src/year2024/mod.rs").exists());
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn fetches_input_of_new_day() {
+        let root = crate_root("new-day", &[]);
+        new_day(&root, 2023, 3).unwrap();
+
+        let (url, server) = serve_once("1 2 3\n");
+        let client = Client::with_http(UreqHttp::default(), url, "secret".to_owned());
+        let path = fetch_input(&client, &root.join("inputs"), 2023, 3).unwrap();
+        server.join().unwrap();
+
+        assert_eq!("1 2 3\n", std::fs::read_to_string(path).unwrap());
+        assert!(fetch_input(&client, &root.join("inputs"), 2023, 3).is_err());
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
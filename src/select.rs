@@ -0,0 +1,177 @@
+use anyhow::{bail, ensure, Context, Result};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Set of days, given as comma separated days and inclusive ranges, like `1,5,10-15`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Days(Vec<usize>);
+
+impl Days {
+    pub fn contains(&self, day: usize) -> bool {
+        self.0.contains(&day)
+    }
+
+    /// Returns the only day in the set, if there is exactly one.
+    pub fn single(&self) -> Option<usize> {
+        match self.0.as_slice() {
+            [day] => Some(*day),
+            _ => None,
+        }
+    }
+}
+
+fn parse_day(s: &str) -> Result<usize> {
+    let day: usize = s
+        .trim()
+        .parse()
+        .with_context(|| format!("invalid day '{s}'"))?;
+    ensure!((1..=25).contains(&day), "day {day} is not in 1-25");
+    Ok(day)
+}
+
+impl FromStr for Days {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut days = vec![];
+        for range in s.split(',') {
+            match range.split_once('-') {
+                Some((from, to)) => {
+                    let (from, to) = (parse_day(from)?, parse_day(to)?);
+                    ensure!(from <= to, "empty range '{range}'");
+                    days.extend(from..=to);
+                }
+                None => days.push(parse_day(range)?),
+            }
+        }
+        days.sort_unstable();
+        days.dedup();
+        Ok(Self(days))
+    }
+}
+
+/// Where to read the input of a day from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+}
+
+impl From<&str> for Source {
+    fn from(s: &str) -> Self {
+        if s == "-" {
+            Source::Stdin
+        } else {
+            Source::File(s.into())
+        }
+    }
+}
+
+/// Input of a day given on the command line as `DAY=PATH`, or only `PATH`
+/// when a single day is run. `-` stands for stdin.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputOverride {
+    pub day: Option<usize>,
+    pub source: Source,
+}
+
+impl FromStr for InputOverride {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        if s.is_empty() {
+            bail!("empty input");
+        }
+        Ok(match s.split_once('=') {
+            Some((day, path)) if !day.is_empty() && day.bytes().all(|b| b.is_ascii_digit()) => {
+                Self {
+                    day: Some(parse_day(day)?),
+                    source: path.into(),
+                }
+            }
+            _ => Self {
+                day: None,
+                source: s.into(),
+            },
+        })
+    }
+}
+
+/// Whether `day` is run, given the days selected with `--days` and the input
+/// overrides. Without `--days`, days given an input are the only ones run, or
+/// all days when there are no inputs.
+pub fn is_selected(days: Option<&Days>, inputs: &[InputOverride], day: usize) -> bool {
+    match days {
+        Some(days) => days.contains(day),
+        None => inputs.is_empty() || inputs.iter().any(|input| input.day == Some(day)),
+    }
+}
+
+/// Part of a puzzle, `1` or `2`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Part(usize);
+
+impl Part {
+    pub fn number(self) -> usize {
+        self.0
+    }
+}
+
+impl FromStr for Part {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "1" => Ok(Part(1)),
+            "2" => Ok(Part(2)),
+            _ => bail!("part must be 1 or 2, got '{s}'"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_days_and_ranges() {
+        let days: Days = "1,5,10-12,11".parse().unwrap();
+        assert_eq!(Days(vec![1, 5, 10, 11, 12]), days);
+        assert_eq!(Some(7), "7".parse::<Days>().unwrap().single());
+        assert!("0".parse::<Days>().is_err());
+        assert!("5-3".parse::<Days>().is_err());
+        assert!("1,,2".parse::<Days>().is_err());
+    }
+
+    #[test]
+    fn parses_input_overrides() {
+        assert_eq!(
+            InputOverride {
+                day: Some(12),
+                source: Source::File("inputs/2023/day12_test".into())
+            },
+            "12=inputs/2023/day12_test".parse().unwrap()
+        );
+        assert_eq!(
+            InputOverride {
+                day: Some(3),
+                source: Source::Stdin
+            },
+            "3=-".parse().unwrap()
+        );
+        assert_eq!(
+            InputOverride {
+                day: None,
+                source: Source::File("a=b".into())
+            },
+            "a=b".parse().unwrap()
+        );
+
+        let inputs: Vec<InputOverride> = vec!["12=-".parse().unwrap()];
+        assert!(is_selected(None, &inputs, 12));
+        assert!(!is_selected(None, &inputs, 11));
+        assert!(is_selected(None, &[], 11));
+        let days: Days = "11-12".parse().unwrap();
+        assert!(is_selected(Some(&days), &inputs, 11));
+    }
+}
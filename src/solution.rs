@@ -0,0 +1,159 @@
+use anyhow::{anyhow, Context, Result};
+use rustc_hash::FxHashMap;
+use std::any::Any;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// Answer to a single part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    /// Part without a puzzle, like part two of day 25.
+    None,
+    Num(i64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::None => write!(f, "-"),
+            Answer::Num(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+macro_rules! answer_from_num {
+    ($($t:ty),+) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Num(i64::try_from(value).expect("answer does not fit in i64"))
+                }
+            }
+        )+
+    };
+}
+
+answer_from_num!(i16, i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<()> for Answer {
+    fn from(_: ()) -> Self {
+        Answer::None
+    }
+}
+
+/// Parameters of a puzzle that differ between the examples and the real
+/// input, like the number of steps to take. Real inputs need none.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Params(FxHashMap<String, String>);
+
+impl FromIterator<(String, String)> for Params {
+    fn from_iter<I: IntoIterator<Item = (String, String)>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl Params {
+    /// Returns the value of `key`, or `default` when it is not given.
+    pub fn get<T>(&self, key: &str, default: T) -> Result<T>
+    where
+        T: FromStr,
+        <T as FromStr>::Err: std::error::Error + Send + Sync + 'static,
+    {
+        match self.0.get(key) {
+            Some(value) => value
+                .parse()
+                .with_context(|| format!("cannot parse parameter {key}={value}")),
+            None => Ok(default),
+        }
+    }
+}
+
+/// Single day of the calendar, split into parsing and the two parts.
+pub trait Solution {
+    type Input: Send + Sync + 'static;
+
+    /// Parts are independent and heavy enough to be worth running concurrently.
+    const PARALLEL_PARTS: bool = false;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    /// Like [`Solution::parse`], for days with parameters.
+    fn parse_with(input: &str, _params: &Params) -> Result<Self::Input> {
+        Self::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+}
+
+pub type ParsedInput = Box<dyn Any + Send + Sync>;
+
+/// Object safe version of [`Solution`] used by the registry.
+pub trait DynSolution: Sync {
+    fn parse(&self, input: &str, params: &Params) -> Result<ParsedInput>;
+    fn part1(&self, input: &ParsedInput) -> Result<Answer>;
+    fn part2(&self, input: &ParsedInput) -> Result<Answer>;
+    fn solve(&self, input: &ParsedInput) -> Result<(Answer, Answer)>;
+    /// Like [`DynSolution::solve`], but also returns time spent in each part.
+    fn solve_timed(&self, input: &ParsedInput) -> Result<[(Answer, Duration); 2]>;
+    fn parallel_parts(&self) -> bool;
+}
+
+fn timed(f: impl FnOnce() -> Answer) -> (Answer, Duration) {
+    let start = Instant::now();
+    let answer = f();
+    (answer, start.elapsed())
+}
+
+fn downcast<S: Solution>(input: &ParsedInput) -> Result<&S::Input> {
+    input
+        .downcast_ref()
+        .ok_or_else(|| anyhow!("input parsed by a different solution"))
+}
+
+impl<S: Solution + Sync> DynSolution for S {
+    fn parse(&self, input: &str, params: &Params) -> Result<ParsedInput> {
+        Ok(Box::new(S::parse_with(input, params)?))
+    }
+
+    fn part1(&self, input: &ParsedInput) -> Result<Answer> {
+        Ok(S::part1(downcast::<S>(input)?))
+    }
+
+    fn part2(&self, input: &ParsedInput) -> Result<Answer> {
+        Ok(S::part2(downcast::<S>(input)?))
+    }
+
+    fn solve(&self, input: &ParsedInput) -> Result<(Answer, Answer)> {
+        let input = downcast::<S>(input)?;
+        if S::PARALLEL_PARTS {
+            Ok(rayon::join(|| S::part1(input), || S::part2(input)))
+        } else {
+            Ok((S::part1(input), S::part2(input)))
+        }
+    }
+
+    fn solve_timed(&self, input: &ParsedInput) -> Result<[(Answer, Duration); 2]> {
+        let input = downcast::<S>(input)?;
+        if S::PARALLEL_PARTS {
+            let (part1, part2) =
+                rayon::join(|| timed(|| S::part1(input)), || timed(|| S::part2(input)));
+            Ok([part1, part2])
+        } else {
+            Ok([timed(|| S::part1(input)), timed(|| S::part2(input))])
+        }
+    }
+
+    fn parallel_parts(&self) -> bool {
+        S::PARALLEL_PARTS
+    }
+}
@@ -0,0 +1,70 @@
+use std::time::Duration;
+
+/// Median of already sorted durations.
+pub fn median(array: &[Duration]) -> Duration {
+    if array.len().is_multiple_of(2) {
+        let ind_left = array.len() / 2 - 1;
+        let ind_right = array.len() / 2;
+        (array[ind_left] + array[ind_right]).div_f64(2.0)
+    } else {
+        array[array.len() / 2]
+    }
+}
+
+/// Summary of repeated measurements of the same thing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Returns `None` for no measurements.
+    pub fn new(times: &[Duration]) -> Option<Self> {
+        if times.is_empty() {
+            return None;
+        }
+        let mut sorted = times.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let mean = sorted.iter().sum::<Duration>().div_f64(n as f64);
+        let variance = sorted
+            .iter()
+            .map(|t| (t.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / n as f64;
+        // Nearest rank.
+        let p95 = sorted[(n * 95).div_ceil(100) - 1];
+        Some(Self {
+            runs: n,
+            min: sorted[0],
+            median: median(&sorted),
+            mean,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+            p95,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summarizes_runs() {
+        let times: Vec<_> = [5, 1, 4, 2, 3].map(Duration::from_millis).to_vec();
+        let stats = Stats::new(&times).unwrap();
+
+        assert_eq!(5, stats.runs);
+        assert_eq!(Duration::from_millis(1), stats.min);
+        assert_eq!(Duration::from_millis(3), stats.median);
+        assert_eq!(Duration::from_millis(3), stats.mean);
+        assert_eq!(Duration::from_millis(5), stats.p95);
+        assert!((stats.stddev.as_secs_f64() - 2e-6f64.sqrt()).abs() < 1e-9);
+        assert_eq!(None, Stats::new(&[]));
+    }
+}
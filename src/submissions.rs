@@ -0,0 +1,197 @@
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::answers::Answers;
+use crate::client::{Client, Http, Verdict};
+
+/// An answer submitted for a part of a day, with the response to it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub day: usize,
+    pub part: usize,
+    pub answer: String,
+    pub verdict: Verdict,
+    pub message: String,
+    /// Seconds since the Unix epoch.
+    pub time: u64,
+}
+
+/// Log of all answers submitted for a year, stored as JSON.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Submissions(Vec<Submission>);
+
+impl Submissions {
+    /// Loads the log at `path`, which is empty when the file does not exist.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let file = std::fs::File::open(path)
+            .with_context(|| format!("Failed to read submissions: {}", path.display()))?;
+        serde_json::from_reader(std::io::BufReader::new(file))
+            .map(Self)
+            .with_context(|| format!("Failed to parse submissions: {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let file = std::fs::File::create(path)
+            .with_context(|| format!("Failed to create submissions: {}", path.display()))?;
+        serde_json::to_writer_pretty(file, &self.0)
+            .with_context(|| format!("Failed to write submissions: {}", path.display()))
+    }
+
+    fn of(&self, day: usize, part: usize) -> impl Iterator<Item = &Submission> {
+        self.0
+            .iter()
+            .filter(move |s| s.day == day && s.part == part)
+    }
+
+    /// Returns the answer of `part` of `day` confirmed as correct.
+    pub fn correct(&self, day: usize, part: usize) -> Option<&str> {
+        self.of(day, part)
+            .find(|s| s.verdict == Verdict::Correct)
+            .map(|s| s.answer.as_str())
+    }
+
+    /// Returns the lowest answer known to be too high and the highest answer
+    /// known to be too low for `part` of `day`.
+    pub fn bounds(&self, day: usize, part: usize) -> (Option<i64>, Option<i64>) {
+        let rejected = |verdict| {
+            self.of(day, part)
+                .filter(move |s| s.verdict == verdict)
+                .filter_map(|s| s.answer.parse::<i64>().ok())
+        };
+        (
+            rejected(Verdict::TooHigh).min(),
+            rejected(Verdict::TooLow).max(),
+        )
+    }
+
+    /// Fails when submitting `answer` to `part` of `day` is known to be
+    /// pointless: the part is solved, or the answer was already rejected or
+    /// is out of bounds of earlier answers.
+    pub fn check(&self, day: usize, part: usize, answer: &str) -> Result<()> {
+        if let Some(correct) = self.correct(day, part) {
+            bail!("day {day} part {part} is already solved with {correct}");
+        }
+        if let Some(rejected) = self
+            .of(day, part)
+            .find(|s| s.answer == answer && s.verdict.is_rejection())
+        {
+            bail!("{answer} was already rejected as {}", rejected.verdict);
+        }
+        if let Ok(value) = answer.parse::<i64>() {
+            match self.bounds(day, part) {
+                (Some(high), _) if value >= high => {
+                    bail!("{answer} is not below {high}, which is too high")
+                }
+                (_, Some(low)) if value <= low => {
+                    bail!("{answer} is not above {low}, which is too low")
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    pub fn record(&mut self, submission: Submission) {
+        self.0.push(submission);
+    }
+
+    /// Adds answers confirmed as correct to `answers` of the default inputs,
+    /// for parts that have no expected answer yet.
+    pub fn confirm(&self, answers: &mut Answers) {
+        for s in self.0.iter().filter(|s| s.verdict == Verdict::Correct) {
+            answers.confirm(&format!("day{:02}", s.day), s.part, &s.answer);
+        }
+    }
+}
+
+/// Submits `answer` to `part` of `day` of `year` unless `log` tells it cannot
+/// be correct, and records the response in `log`.
+pub fn submit<H: Http>(
+    client: &Client<H>,
+    log: &mut Submissions,
+    year: u16,
+    day: usize,
+    part: usize,
+    answer: &str,
+) -> Result<Submission> {
+    log.check(day, part, answer)?;
+    let response = client.submit(year, day, part, answer)?;
+    let submission = Submission {
+        day,
+        part,
+        answer: answer.to_owned(),
+        verdict: response.verdict,
+        message: response.message,
+        time: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |t| t.as_secs()),
+    };
+    log.record(submission.clone());
+    Ok(submission)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::stub::serve_once;
+    use crate::client::UreqHttp;
+
+    fn submission(part: usize, answer: &str, verdict: Verdict) -> Submission {
+        Submission {
+            day: 3,
+            part,
+            answer: answer.to_owned(),
+            verdict,
+            message: String::new(),
+            time: 0,
+        }
+    }
+
+    #[test]
+    fn refuses_pointless_answers() {
+        let mut log = Submissions::default();
+        log.record(submission(1, "100", Verdict::TooHigh));
+        log.record(submission(1, "80", Verdict::TooHigh));
+        log.record(submission(1, "10", Verdict::TooLow));
+        log.record(submission(1, "50", Verdict::Wrong));
+        log.record(submission(2, "abc", Verdict::Correct));
+
+        assert_eq!((Some(80), Some(10)), log.bounds(3, 1));
+        assert!(log.check(3, 1, "80").is_err());
+        assert!(log.check(3, 1, "90").is_err());
+        assert!(log.check(3, 1, "10").is_err());
+        assert!(log.check(3, 1, "50").is_err());
+        assert!(log.check(3, 1, "79").is_ok());
+        assert!(log.check(3, 2, "def").is_err());
+        assert!(log.check(4, 1, "90").is_ok());
+
+        let mut answers: Answers = "day03 - -".parse().unwrap();
+        log.confirm(&mut answers);
+        assert!(!answers.is_checked("day03", 1));
+        assert_eq!(
+            Some(&["-".to_owned(), "abc".to_owned()][..]),
+            answers.get("day03")
+        );
+    }
+
+    #[test]
+    fn records_response() {
+        let (url, server) = serve_once("<article><p>That's the right answer!</p></article>");
+        let client = Client::with_http(UreqHttp::default(), url, "secret".to_owned());
+        let mut log = Submissions::default();
+        log.record(submission(1, "7", Verdict::TooLow));
+
+        assert!(submit(&client, &mut log, 2023, 3, 1, "6").is_err());
+        let submission = submit(&client, &mut log, 2023, 3, 1, "8").unwrap();
+        server.join().unwrap();
+
+        assert_eq!(Verdict::Correct, submission.verdict);
+        assert_eq!(Some("8"), log.correct(3, 1));
+        assert!(log.check(3, 1, "9").is_err());
+    }
+}
@@ -1,13 +1,19 @@
-use std::{fmt::Display, ops::Deref, str::FromStr};
+use anyhow::{ensure, Context, Result};
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::ops::{Deref, Index, IndexMut};
+use std::str::FromStr;
 
 use smallvec::{smallvec, SmallVec};
 
+use crate::pos::{Coord, Dir4, Dir8, Pos};
+
 pub fn transpose<T: Clone, const N: usize>(original: &[SmallVec<[T; N]>]) -> Vec<SmallVec<[T; N]>> {
     assert!(!original.is_empty());
     let mut transposed: Vec<SmallVec<[T; N]>> = vec![smallvec![]; original[0].len()];
 
     for original_row in original {
-        for (item, transposed_row) in original_row.into_iter().zip(&mut transposed) {
+        for (item, transposed_row) in original_row.iter().zip(&mut transposed) {
             transposed_row.push(item.clone());
         }
     }
@@ -19,7 +25,7 @@ pub fn transpose_vec<T: Clone>(original: &[Vec<T>]) -> Vec<Vec<T>> {
     let mut transposed: Vec<Vec<T>> = vec![vec![]; original[0].len()];
 
     for original_row in original {
-        for (item, transposed_row) in original_row.into_iter().zip(&mut transposed) {
+        for (item, transposed_row) in original_row.iter().zip(&mut transposed) {
             transposed_row.push(item.clone());
         }
     }
@@ -40,7 +46,7 @@ impl Deref for StrVec {
 impl Display for StrVec {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let s: String = self.0.iter().map(|c| *c as char).collect();
-        s.fmt(f)
+        Display::fmt(&s, f)
     }
 }
 
@@ -52,9 +58,293 @@ impl std::fmt::Debug for StrVec {
 }
 
 impl FromStr for StrVec {
-    type Err = ();
+    type Err = std::convert::Infallible;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
         Ok(Self(s.bytes().collect()))
     }
 }
+
+fn coord<P: Coord>(v: usize) -> P {
+    P::try_from(v).ok().expect("grid coordinate out of range")
+}
+
+/// Rectangular grid stored row by row in a single `Vec`, indexed by [`Pos`]
+/// with `x` as the column and `y` as the row.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(width * height, cells.len(), "grid cells do not fill it");
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Builds a grid from rows of equal length, like those of [`StrVec`]s or
+    /// the result of [`transpose`].
+    pub fn from_rows<R: IntoIterator<Item = T>>(rows: impl IntoIterator<Item = R>) -> Result<Self> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+        for row in rows {
+            let len = cells.len();
+            cells.extend(row);
+            let row_width = cells.len() - len;
+            match width {
+                None => width = Some(row_width),
+                Some(width) => ensure!(
+                    width == row_width,
+                    "row {} has {row_width} cells instead of {width}",
+                    height + 1
+                ),
+            }
+            height += 1;
+        }
+        Ok(Self::new(width.unwrap_or(0), height, cells))
+    }
+
+    /// Parses non empty lines of `input` as rows, with `f` mapping each byte
+    /// to a cell. Errors name the line and column of the cell.
+    pub fn parse_with(input: &str, f: impl Fn(u8) -> Result<T>) -> Result<Self> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+        for (i, line) in input.lines().enumerate().filter(|(_, l)| !l.is_empty()) {
+            for (j, b) in line.bytes().enumerate() {
+                cells.push(f(b).with_context(|| format!("line {}, column {}", i + 1, j + 1))?);
+            }
+            let width = *width.get_or_insert(line.len());
+            ensure!(
+                line.len() == width,
+                "line {}: {} cells instead of {width}",
+                i + 1,
+                line.len()
+            );
+            height += 1;
+        }
+        Ok(Self::new(width.unwrap_or(0), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    fn index_of<P: Coord>(&self, pos: Pos<P>) -> Option<usize> {
+        let x: usize = pos.x.try_into().ok()?;
+        let y: usize = pos.y.try_into().ok()?;
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    pub fn contains<P: Coord>(&self, pos: Pos<P>) -> bool {
+        self.index_of(pos).is_some()
+    }
+
+    pub fn get<P: Coord>(&self, pos: Pos<P>) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut<P: Coord>(&mut self, pos: Pos<P>) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} is out of the grid");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// All positions, row by row.
+    pub fn positions<P: Coord>(&self) -> impl Iterator<Item = Pos<P>> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| {
+            (0..width).map(move |x| Pos {
+                x: coord(x),
+                y: coord(y),
+            })
+        })
+    }
+
+    fn neighbours<P: Coord>(
+        &self,
+        pos: Pos<P>,
+        deltas: impl Iterator<Item = Pos<i8>> + 'static,
+    ) -> impl Iterator<Item = Pos<P>> + '_ {
+        deltas
+            .map(move |d| Pos {
+                x: pos.x + d.x.into(),
+                y: pos.y + d.y.into(),
+            })
+            .filter(|p| self.contains(*p))
+    }
+
+    /// Positions in the grid that share an edge with `pos`.
+    pub fn neighbours4<P: Coord>(&self, pos: Pos<P>) -> impl Iterator<Item = Pos<P>> + '_ {
+        self.neighbours(pos, Dir4::ALL.into_iter().map(Pos::from))
+    }
+
+    /// Positions in the grid that share an edge or a corner with `pos`.
+    pub fn neighbours8<P: Coord>(&self, pos: Pos<P>) -> impl Iterator<Item = Pos<P>> + '_ {
+        self.neighbours(pos, Dir8::ALL.into_iter().map(Pos::from))
+    }
+
+    /// Position of the first cell equal to `value`, row by row.
+    pub fn find<P: Coord>(&self, value: T) -> Option<Pos<P>>
+    where
+        T: PartialEq,
+    {
+        let i = self.cells.iter().position(|c| *c == value)?;
+        Some(Pos {
+            x: coord(i % self.width),
+            y: coord(i / self.width),
+        })
+    }
+
+    pub fn transposed(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .flat_map(|x| self.column(x).cloned())
+            .collect();
+        Self::new(self.height, self.width, cells)
+    }
+
+    /// The grid rotated clockwise by a quarter turn.
+    pub fn rotated(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .flat_map(|x| (0..self.height).rev().map(move |y| (x, y)))
+            .map(|(x, y)| self.cells[y * self.width + x].clone())
+            .collect();
+        Self::new(self.height, self.width, cells)
+    }
+}
+
+impl<T, P: Coord> Index<Pos<P>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos<P>) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("({:?}, {:?}) is out of the grid", pos.x, pos.y))
+    }
+}
+
+impl<T, P: Coord> IndexMut<Pos<P>> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos<P>) -> &mut T {
+        let i = self
+            .index_of(pos)
+            .unwrap_or_else(|| panic!("({:?}, {:?}) is out of the grid", pos.x, pos.y));
+        &mut self.cells[i]
+    }
+}
+
+impl Grid<u8> {
+    /// Parses `input` like [`FromStr`], but fails on bytes not in `allowed`.
+    pub fn parse_allowed(input: &str, allowed: &[u8]) -> Result<Self> {
+        Self::parse_with(input, |b| {
+            ensure!(allowed.contains(&b), "unexpected '{}'", b.escape_ascii());
+            Ok(b)
+        })
+    }
+}
+
+impl FromStr for Grid<u8> {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse_with(s, Ok)
+    }
+}
+
+impl Display for Grid<u8> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", row.iter().map(|c| *c as char).collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Grid<char> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", row.iter().collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Debug> Debug for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.rows()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type P = Pos<i32>;
+
+    #[test]
+    fn grid_views_and_turns() {
+        let grid: Grid<u8> = "ab.\nS.c\n".parse().unwrap();
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(Some(&b'c'), grid.get(P { x: 2, y: 1 }));
+        assert_eq!(None, grid.get(P { x: 3, y: 1 }));
+        assert_eq!(None, grid.get(P { x: -1, y: 0 }));
+        assert_eq!(Some(P { x: 0, y: 1 }), grid.find(b'S'));
+        assert_eq!(b"S.c", grid.row(1));
+        assert_eq!(vec![&b'b', &b'.'], grid.column(1).collect::<Vec<_>>());
+        assert_eq!(
+            vec![P { x: 1, y: 0 }, P { x: 0, y: 1 }],
+            grid.neighbours4(P { x: 0, y: 0 }).collect::<Vec<_>>()
+        );
+        assert_eq!(5, grid.neighbours8(P { x: 1, y: 1 }).count());
+        assert_eq!("aS\nb.\n.c\n", grid.transposed().to_string());
+        assert_eq!("Sa\n.b\nc.\n", grid.rotated().to_string());
+        assert!("ab\nc\n".parse::<Grid<u8>>().is_err());
+        assert_eq!(
+            "line 3, column 2: unexpected 'x'",
+            format!("{:#}", Grid::parse_allowed(".#\n\n#x", b".#").unwrap_err())
+        );
+
+        let rows: Vec<StrVec> = vec!["ab.".parse().unwrap(), "S.c".parse().unwrap()];
+        let from_str_vecs = Grid::from_rows(rows.iter().map(|r| r.iter().copied())).unwrap();
+        assert_eq!(grid, from_str_vecs);
+        let rows: Vec<SmallVec<[u8; 3]>> = grid.rows().map(|r| r.into()).collect();
+        assert_eq!(
+            grid.transposed(),
+            Grid::from_rows(transpose(&rows)).unwrap()
+        );
+    }
+}
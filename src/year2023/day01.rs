@@ -0,0 +1,58 @@
+use anyhow::Result;
+
+use crate::input::try_tokens;
+use crate::solution::{Answer, Solution};
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+// Digit starting at `i`, spelled out as a word too when `words` is set.
+fn digit_at(s: &[u8], i: usize, words: bool) -> Option<u32> {
+    if s[i].is_ascii_digit() {
+        return Some((s[i] - b'0') as u32);
+    }
+    if !words {
+        return None;
+    }
+    WORDS
+        .iter()
+        .position(|w| s[i..].starts_with(w.as_bytes()))
+        .map(|d| d as u32 + 1)
+}
+
+fn calibration_value(line: &str, words: bool) -> u32 {
+    let s = line.as_bytes();
+    let first = (0..s.len()).find_map(|i| digit_at(s, i, words)).unwrap();
+    let last = (0..s.len())
+        .rev()
+        .find_map(|i| digit_at(s, i, words))
+        .unwrap();
+    first * 10 + last
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        try_tokens(input, None)
+    }
+
+    fn part1(lines: &Self::Input) -> Answer {
+        lines
+            .iter()
+            .map(|l| calibration_value(l, false))
+            .sum::<u32>()
+            .into()
+    }
+
+    fn part2(lines: &Self::Input) -> Answer {
+        lines
+            .iter()
+            .map(|l| calibration_value(l, true))
+            .sum::<u32>()
+            .into()
+    }
+}
@@ -0,0 +1,76 @@
+use anyhow::{ensure, Context, Result};
+use rustc_hash::FxHashMap;
+
+use crate::input::try_lines;
+use crate::solution::{Answer, Solution};
+
+type Game = Vec<FxHashMap<String, i64>>;
+
+fn parse(s: &str) -> Result<FxHashMap<String, i64>> {
+    s.split(", ")
+        .map(|g| {
+            let (num, name) = g
+                .split_once(' ')
+                .with_context(|| format!("'{g}' is not a number of cubes"))?;
+            ensure!(
+                ["red", "green", "blue"].contains(&name),
+                "unknown colour '{name}'"
+            );
+            let num = num
+                .parse()
+                .with_context(|| format!("cannot parse '{num}'"))?;
+            Ok((name.to_owned(), num))
+        })
+        .collect()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        try_lines(input, |line| {
+            let (_, game) = line.split_once(": ").context("missing ': '")?;
+            game.split("; ").map(parse).collect()
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let limits: FxHashMap<_, _> = [("red", 12), ("green", 13), ("blue", 14)]
+            .into_iter()
+            .collect();
+
+        input
+            .iter()
+            .enumerate()
+            .filter(|(_, game)| {
+                game.iter().all(|group| {
+                    group
+                        .iter()
+                        .all(|(name, num)| limits[name.as_str()] >= *num)
+                })
+            })
+            .map(|(id, _)| id + 1)
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .map(|game| {
+                let mut max: FxHashMap<&str, i64> = Default::default();
+                for group in game {
+                    for (name, num) in group {
+                        max.entry(name)
+                            .and_modify(|m| *m = (*m).max(*num))
+                            .or_insert(*num);
+                    }
+                }
+                max.into_values().product::<i64>()
+            })
+            .sum::<i64>()
+            .into()
+    }
+}
@@ -0,0 +1,83 @@
+use anyhow::Result;
+use rustc_hash::{FxHashMap, FxHashSet};
+use smallvec::SmallVec;
+
+use crate::solution::{Answer, Solution};
+use crate::vec::Grid;
+
+type Pos = crate::pos::Pos<i64>;
+
+type Gears = SmallVec<[Pos; 12]>;
+
+// Returns every part number together with the gears it touches.
+fn part_numbers(input: &Grid<u8>) -> Vec<(i64, Gears)> {
+    let mut ret = vec![];
+    let mut current_digs = vec![];
+    let mut current_pos = vec![];
+    for (row_id, row) in input.rows().enumerate() {
+        for (col_id, c) in row.iter().enumerate() {
+            if c.is_ascii_digit() {
+                current_digs.push(*c);
+                current_pos.push(Pos::new(col_id as i64, row_id as i64));
+            }
+            if (!c.is_ascii_digit() || col_id == (row.len() - 1)) && !current_digs.is_empty() {
+                let mut is_part_num = false;
+                let mut candidate_gears: Gears = Default::default();
+                for p in current_pos.iter() {
+                    for p in input.neighbours8(*p) {
+                        let c = input[p];
+                        if !c.is_ascii_digit() && c != b'.' {
+                            is_part_num = true;
+                        }
+                        if c == b'*' {
+                            candidate_gears.push(p);
+                        }
+                    }
+                }
+                if is_part_num {
+                    let num: i64 = current_digs
+                        .iter()
+                        .copied()
+                        .fold(0, |a, v| a * 10 + (v - b'0') as i64);
+                    ret.push((num, candidate_gears));
+                }
+                current_digs.clear();
+                current_pos.clear();
+            }
+        }
+    }
+    ret
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part_numbers(input)
+            .into_iter()
+            .map(|(num, _)| num)
+            .sum::<i64>()
+            .into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let mut gears: FxHashMap<Pos, FxHashSet<i64>> = Default::default();
+        for (num, candidate_gears) in part_numbers(input) {
+            for c in candidate_gears {
+                gears.entry(c).or_default().insert(num);
+            }
+        }
+        gears
+            .into_values()
+            .filter(|v| v.len() == 2)
+            .map(|v| v.into_iter().product::<i64>())
+            .sum::<i64>()
+            .into()
+    }
+}
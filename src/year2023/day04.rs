@@ -0,0 +1,88 @@
+use anyhow::{ensure, Context, Result};
+use std::mem::swap;
+
+use crate::input::{try_lines, try_tokens};
+use crate::solution::{Answer, Solution};
+
+type Card = (Vec<i64>, Vec<i64>);
+
+fn matching(cards: &[Card]) -> impl Iterator<Item = usize> + '_ {
+    cards
+        .iter()
+        .map(|(winning, have)| have.iter().filter(|c| winning.contains(c)).count())
+}
+
+fn parse_card(line: &str) -> Result<Card> {
+    let (_, numbers) = line.split_once(':').context("missing ':'")?;
+    let (winning, have) = numbers.split_once('|').context("missing '|'")?;
+    Ok((try_tokens(winning, None)?, try_tokens(have, None)?))
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut sizes = None;
+        let cards = try_lines(input, |line| {
+            let card = parse_card(line)?;
+            let (winning, have) = *sizes.get_or_insert((card.0.len(), card.1.len()));
+            ensure!(
+                (card.0.len(), card.1.len()) == (winning, have),
+                "card has {} winning numbers and {} numbers instead of {winning} and {have}",
+                card.0.len(),
+                card.1.len()
+            );
+            Ok(card)
+        })?;
+        for (i, count) in matching(&cards).enumerate() {
+            ensure!(
+                i + count < cards.len(),
+                "card {} wins copies of cards past the last one",
+                i + 1
+            );
+        }
+        Ok(cards)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        matching(input)
+            .map(|count| {
+                if count == 0 {
+                    0
+                } else {
+                    2usize.pow(count as u32 - 1)
+                }
+            })
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let mut cards_won: Vec<usize> = vec![0];
+        cards_won.extend(matching(input));
+
+        let mut todo: Vec<usize> = vec![1; input.len() + 1];
+        todo[0] = 0;
+        let mut todo_next: Vec<usize> = vec![0; input.len() + 1];
+
+        let mut part2 = 0;
+        loop {
+            let part2_start = part2;
+            for (card, count) in todo.iter().enumerate() {
+                part2 += count;
+                for next in (1..=cards_won[card]).map(|v| card + v) {
+                    todo_next[next] += count;
+                }
+            }
+            if part2_start == part2 {
+                break;
+            }
+            swap(&mut todo, &mut todo_next);
+            todo_next.fill(0);
+        }
+
+        part2.into()
+    }
+}
@@ -0,0 +1,119 @@
+use anyhow::{bail, ensure, Context, Result};
+use std::ops::Range;
+
+use crate::input::{try_lines, try_tokens};
+use crate::solution::{Answer, Solution};
+
+type Map = Vec<(Range<i64>, i64)>;
+
+pub struct Almanac {
+    seeds: Vec<i64>,
+    maps: Vec<Map>,
+}
+
+fn convert(maps: &[Map], mut num: i64) -> i64 {
+    for map in maps {
+        for (range, dst) in map {
+            if range.contains(&num) {
+                num = dst + num - range.start;
+                break;
+            }
+        }
+    }
+    num
+}
+
+fn convert_inv(maps: &[Map], num: i64, depth: i64) -> i64 {
+    convert(&maps[(maps.len() - depth as usize)..], num)
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut seeds: Option<Vec<i64>> = None;
+        let mut maps: Vec<Map> = vec![];
+        try_lines(input, |line| {
+            if let Some(numbers) = line.strip_prefix("seeds:") {
+                ensure!(seeds.is_none(), "seeds are given again");
+                seeds = Some(try_tokens(numbers, None)?);
+            } else if line.ends_with(" map:") {
+                maps.push(vec![]);
+            } else {
+                let map = maps.last_mut().context("range outside of a map")?;
+                let [dst, src, len] = try_tokens::<i64>(line, None)?[..] else {
+                    bail!("range is not three numbers")
+                };
+                map.push((src..(src + len), dst));
+            }
+            Ok(())
+        })?;
+        let seeds = seeds.context("missing seeds")?;
+        ensure!(
+            !seeds.is_empty() && seeds.len().is_multiple_of(2),
+            "seeds are not pairs of start and length"
+        );
+        ensure!(!maps.is_empty(), "missing maps");
+
+        Ok(Almanac { seeds, maps })
+    }
+
+    fn part1(Almanac { seeds, maps }: &Self::Input) -> Answer {
+        seeds
+            .iter()
+            .map(|s| convert(maps, *s))
+            .min()
+            .unwrap()
+            .into()
+    }
+
+    fn part2(Almanac { seeds, maps }: &Self::Input) -> Answer {
+        let mut maps_inv: Vec<Map> = maps.to_vec();
+        maps_inv.reverse();
+        let maps_inv: Vec<Map> = maps_inv
+            .iter()
+            .map(|map| {
+                map.iter()
+                    .map(|(r, dst)| {
+                        let len = r.end - r.start;
+                        let src = r.start;
+                        (*dst..(dst + len), src)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let inverted_boundaries: Vec<i64> = maps
+            .iter()
+            .enumerate()
+            .flat_map(|(depth, map)| map.iter().map(move |(r, _)| (depth, r.clone())))
+            .flat_map(|(d, r)| [(d, r.start), (d, r.end - 1)])
+            .map(|(d, v)| convert_inv(&maps_inv, v, d as i64))
+            .collect();
+
+        seeds
+            .chunks(2)
+            .map(|c| {
+                let r = c[0]..(c[0] + c[1]);
+                let mut to_check = inverted_boundaries.clone();
+                to_check.push(c[0]);
+                to_check.push(c[0] + c[1] - 1);
+                to_check
+                    .into_iter()
+                    .flat_map(|p| {
+                        if r.contains(&p) {
+                            Some(convert(maps, p))
+                        } else {
+                            None
+                        }
+                    })
+                    .min()
+                    .unwrap()
+            })
+            .min()
+            .unwrap()
+            .into()
+    }
+}
@@ -0,0 +1,98 @@
+use anyhow::{bail, ensure, Context, Result};
+use std::cmp::Ordering;
+
+use crate::input::{try_lines, try_tokens};
+use crate::solution::{Answer, Solution};
+
+fn binary_search_by<F>(first: i64, last: i64, mut f: F) -> Result<i64, i64>
+where
+    F: FnMut(i64) -> Ordering,
+{
+    use Ordering::*;
+    let mut size = last - first + 1;
+    let mut left = first;
+    let mut right = last + 1;
+    while left < right {
+        let mid = left + size / 2;
+        match f(mid) {
+            Less => {
+                left = mid + 1;
+            }
+            Greater => {
+                right = mid;
+            }
+            Equal => return Ok(mid),
+        }
+
+        size = right - left;
+    }
+
+    Err(left)
+}
+
+fn either_way<T>(r: Result<T, T>) -> T {
+    match r {
+        Ok(v) => v,
+        Err(v) => v,
+    }
+}
+
+fn ways_to_win_race(t: i64, record: i64) -> i64 {
+    let will_win = |press_time: i64| ((t - press_time) * press_time).cmp(&record);
+    // Matching the record exactly is not a win.
+    let first_win = match binary_search_by(0, t / 2, will_win) {
+        Ok(v) => v + 1,
+        Err(v) => v,
+    };
+    let last_win = either_way(binary_search_by(t / 2, t, |p| will_win(p).reverse()));
+
+    last_win - first_win
+}
+
+fn join_digits(nums: &[i64]) -> i64 {
+    nums.iter()
+        .map(|n| n.to_string())
+        .collect::<String>()
+        .parse()
+        .unwrap()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let rows = try_lines(input, |line| {
+            let (label, numbers) = line.split_once(':').context("missing ':'")?;
+            try_tokens(numbers, None).context(label.to_owned())
+        })?;
+        let [times, distances] = &rows[..] else {
+            bail!(
+                "expected a Time and a Distance line, got {} lines",
+                rows.len()
+            );
+        };
+        ensure!(
+            !times.is_empty() && times.len() == distances.len(),
+            "line 2: {} distances for {} times",
+            distances.len(),
+            times.len()
+        );
+        Ok(rows)
+    }
+
+    fn part1(races: &Self::Input) -> Answer {
+        (0..races[0].len())
+            .map(|r| ways_to_win_race(races[0][r], races[1][r]))
+            .product::<i64>()
+            .into()
+    }
+
+    fn part2(races: &Self::Input) -> Answer {
+        let t = join_digits(&races[0]);
+        let record_distance = join_digits(&races[1]);
+
+        ways_to_win_race(t, record_distance).into()
+    }
+}
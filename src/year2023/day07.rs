@@ -1,11 +1,9 @@
-use anyhow::Result;
+use anyhow::{ensure, Context, Result};
 use smallvec::SmallVec;
-use std::{
-    cmp::Ordering,
-    time::{Duration, Instant},
-};
+use std::cmp::Ordering;
 
-use crate::input::tokens;
+use crate::input::try_lines;
+use crate::solution::{Answer, Solution};
 
 type Cards = SmallVec<[u8; 5]>;
 
@@ -28,7 +26,7 @@ enum Kind {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-struct Hand {
+pub struct Hand {
     kind: Kind,
     cards: Cards,
 }
@@ -53,12 +51,15 @@ impl Hand {
     }
 }
 
-fn parse(s: &str) -> Hand {
-    assert!(s.len() == 5);
+fn parse(s: &str) -> Result<Hand> {
+    ensure!(
+        s.len() == 5 && s.bytes().all(|c| CARDS.contains(&c)),
+        "'{s}' is not a hand of five cards"
+    );
     let cards: Cards = s.bytes().collect();
 
     let k = kind(&cards);
-    Hand { kind: k, cards }
+    Ok(Hand { kind: k, cards })
 }
 
 fn kind(cards: &Cards) -> Kind {
@@ -81,9 +82,9 @@ fn kind(cards: &Cards) -> Kind {
         4 => Kind::OnePair,
         2 => {
             if counts[0] == 4 || counts[1] == 4 {
-                return Kind::Four;
+                Kind::Four
             } else {
-                return Kind::Full;
+                Kind::Full
             }
         }
         1 => Kind::Five,
@@ -98,7 +99,7 @@ fn kind(cards: &Cards) -> Kind {
 }
 
 fn find_best_kind(h: &Hand) -> Kind {
-    if h.cards.iter().any(|c| *c == b'J') {
+    if h.cards.contains(&b'J') {
         CARDS_CANDIDATES
             .iter()
             .map(|candidate| {
@@ -130,7 +131,7 @@ fn total_winnings(hands: &[(Hand, i64)]) -> i64 {
         .sum()
 }
 
-pub fn solve(input: &str, verify_expected: bool, output: bool) -> Result<Duration> {
+fn scores(joker: bool) -> [i8; 128] {
     let mut scores = [-100i8; 128];
     scores[b'2' as usize] = 0;
     scores[b'3' as usize] = 1;
@@ -141,46 +142,49 @@ pub fn solve(input: &str, verify_expected: bool, output: bool) -> Result<Duratio
     scores[b'8' as usize] = 6;
     scores[b'9' as usize] = 7;
     scores[b'T' as usize] = 8;
-    scores[b'J' as usize] = 9;
+    scores[b'J' as usize] = if joker { -1 } else { 9 };
     scores[b'Q' as usize] = 10;
     scores[b'K' as usize] = 11;
     scores[b'A' as usize] = 12;
+    scores
+}
 
-    let input: Vec<String> = tokens(input, None);
-    let mut input: Vec<(Hand, i64)> = input
-        .chunks(2)
-        .map(|v| {
-            let hand = parse(&v[0]);
-            let bid = v[1].parse().unwrap();
-            (hand, bid)
-        })
-        .collect();
-    let s = Instant::now();
+pub struct Day07;
 
-    input.sort_unstable_by(|(left_hand, _), (right_hand, _)| left_hand.cmp(&right_hand, &scores));
-    let part1 = total_winnings(&input);
+impl Solution for Day07 {
+    type Input = Vec<(Hand, i64)>;
 
-    let mut hands: Vec<(Hand, i64)> = input
-        .into_iter()
-        .map(|(mut h, bid)| {
-            h.kind = find_best_kind(&h);
-            (h, bid)
+    fn parse(input: &str) -> Result<Self::Input> {
+        try_lines(input, |line| {
+            let (hand, bid) = line.split_once(' ').context("missing bid")?;
+            let bid = bid
+                .parse()
+                .with_context(|| format!("cannot parse bid '{bid}'"))?;
+            Ok((parse(hand)?, bid))
         })
-        .collect();
+    }
 
-    scores[b'J' as usize] = -1;
-    hands.sort_unstable_by(|(left_hand, _), (right_hand, _)| left_hand.cmp(&right_hand, &scores));
-    let part2 = total_winnings(&hands);
+    fn part1(input: &Self::Input) -> Answer {
+        let scores = scores(false);
+        let mut hands = input.clone();
+        hands
+            .sort_unstable_by(|(left_hand, _), (right_hand, _)| left_hand.cmp(right_hand, &scores));
+        total_winnings(&hands).into()
+    }
 
-    let e = s.elapsed();
+    fn part2(input: &Self::Input) -> Answer {
+        let scores = scores(true);
+        let mut hands: Vec<(Hand, i64)> = input
+            .iter()
+            .cloned()
+            .map(|(mut h, bid)| {
+                h.kind = find_best_kind(&h);
+                (h, bid)
+            })
+            .collect();
 
-    if verify_expected {
-        assert_eq!(241344943, part1);
-        assert_eq!(243101568, part2);
-    }
-    if output {
-        println!("\t{}", part1);
-        println!("\t{}", part2);
+        hands
+            .sort_unstable_by(|(left_hand, _), (right_hand, _)| left_hand.cmp(right_hand, &scores));
+        total_winnings(&hands).into()
     }
-    Ok(e)
 }
@@ -0,0 +1,110 @@
+use anyhow::{ensure, Context, Result};
+
+use crate::input::parse_lines;
+use crate::solution::{Answer, Solution};
+
+type Node = u16;
+
+// Node names are three digits or capital letters, read as a base 36 number.
+fn to_node(name: &str) -> Result<Node> {
+    ensure!(name.len() == 3, "'{name}' is not a node name");
+    name.chars().try_fold(0, |a, c| {
+        let digit = c
+            .to_digit(36)
+            .filter(|_| !c.is_ascii_lowercase())
+            .with_context(|| format!("'{name}' is not a node name"))?;
+        Ok(a * 36 + digit as Node)
+    })
+}
+
+// Whether the name of `node` ends with `c`.
+fn ends_with(node: Node, c: char) -> bool {
+    node % 36 == c.to_digit(36).unwrap() as Node
+}
+
+pub struct Network {
+    dirs: String,
+    keys: Vec<Node>,
+    nodes: Vec<(Node, Node)>,
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Network;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (dirs, input) = input.split_once("\n\n").context("missing nodes")?;
+        let input: Vec<(String, String, String)> = parse_lines(input, "{} = ({}, {})")?;
+        let keys: Vec<Node> = input
+            .iter()
+            .map(|(key, _, _)| to_node(key))
+            .collect::<Result<_>>()?;
+        let max = *keys.iter().max().context("no nodes")? + 1;
+        let mut nodes: Vec<(Node, Node)> = vec![(0, 0); max as usize];
+        for (key, left, right) in &input {
+            let (left, right) = (to_node(left)?, to_node(right)?);
+            ensure!(
+                left < max && right < max,
+                "{key} leads to a node without a line"
+            );
+            nodes[to_node(key)? as usize] = (left, right);
+        }
+        let dirs = dirs.trim().to_owned();
+        Ok(Network { dirs, keys, nodes })
+    }
+
+    fn part1(Network { dirs, nodes, .. }: &Self::Input) -> Answer {
+        let mut current = to_node("AAA").unwrap();
+        let end = to_node("ZZZ").unwrap();
+        let mut part1 = 0u64;
+        for dir in dirs.chars().cycle() {
+            if current == end {
+                break;
+            }
+            part1 += 1;
+            if dir == 'R' {
+                current = nodes[current as usize].1;
+            } else {
+                current = nodes[current as usize].0;
+            }
+        }
+        part1.into()
+    }
+
+    fn part2(Network { dirs, keys, nodes }: &Self::Input) -> Answer {
+        let mut ghost_current: Vec<(usize, Node)> = keys
+            .iter()
+            .filter(|s| ends_with(**s, 'Z'))
+            .copied()
+            .enumerate()
+            .collect();
+        let mut ends: Vec<Option<u64>> = vec![None; ghost_current.len()];
+
+        let mut cycle_lens = vec![];
+        for (steps, dir) in dirs.chars().cycle().enumerate() {
+            if let Some(idx) = ghost_current.iter().position(|(_, s)| ends_with(*s, 'Z')) {
+                let id = ghost_current[idx].0;
+                if let Some(previous_end) = ends[id] {
+                    cycle_lens.push(steps as u64 - previous_end);
+                    ghost_current.remove(idx);
+                } else {
+                    ends[id] = Some(steps as u64);
+                }
+            }
+
+            if ghost_current.is_empty() {
+                break;
+            }
+
+            for ghost in ghost_current.iter_mut() {
+                let (left, right) = nodes[ghost.1 as usize];
+                ghost.1 = if dir == 'R' { right } else { left };
+            }
+        }
+        cycle_lens
+            .iter()
+            .fold(1, |l, r| num::integer::lcm(l, *r))
+            .into()
+    }
+}
@@ -0,0 +1,51 @@
+use anyhow::{ensure, Result};
+
+use crate::input::{try_lines, try_tokens};
+use crate::solution::{Answer, Solution};
+
+fn find_next(mut nums: Vec<i64>) -> i64 {
+    let mut l = nums.len();
+    while l > 1 {
+        for i in 1..l {
+            nums[i - 1] = nums[i] - nums[i - 1];
+        }
+        l -= 1;
+    }
+    nums.iter().sum::<i64>()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut len = None;
+        try_lines(input, |line| {
+            let history: Vec<i64> = try_tokens(line, None)?;
+            let len = *len.get_or_insert(history.len());
+            ensure!(
+                history.len() == len,
+                "{} values instead of {len}",
+                history.len()
+            );
+            Ok(history)
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input.iter().cloned().map(find_next).sum::<i64>().into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .cloned()
+            .map(|mut v| {
+                v.reverse();
+                find_next(v)
+            })
+            .sum::<i64>()
+            .into()
+    }
+}
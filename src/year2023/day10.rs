@@ -0,0 +1,181 @@
+use anyhow::{ensure, Result};
+use rustc_hash::FxHashSet;
+use smallvec::{smallvec, SmallVec};
+
+use crate::dijkstra::bfs_distances;
+use crate::pos::Dir4::{self, Down, Left, Right, Up};
+use crate::solution::{Answer, Solution};
+use crate::vec::Grid;
+
+type Map = Grid<char>;
+type PosToAllowed = Vec<SmallVec<[(Dir4, SmallVec<[char; 3]>); 4]>>;
+type Pos = crate::pos::Pos<i16>;
+
+#[inline(always)]
+fn get_next(p: Pos, m: &Map, seen: &Grid<bool>, p_to_delta: &PosToAllowed) -> Option<Pos> {
+    for (dir, allowed_chars) in &p_to_delta[m[p] as usize] {
+        let next = p + dir.delta();
+        if let Some(current) = m.get(next) {
+            if allowed_chars.contains(current) && !seen[next] {
+                return Some(next);
+            }
+        }
+    }
+    None
+}
+
+// Returns the tiles of the region of '.' containing `start` and whether it
+// touches the edge of the map.
+fn flood_fill(start: Pos, m: &Map) -> (Vec<Pos>, bool) {
+    let region: Vec<Pos> = bfs_distances(&[start], |p| m.neighbours4(*p).filter(|n| m[*n] == '.'))
+        .into_keys()
+        .collect();
+    let (w, h) = (m.width() as i16, m.height() as i16);
+    let reached_outside = region
+        .iter()
+        .any(|p| p.x == 0 || p.y == 0 || p.x == w - 1 || p.y == h - 1);
+    (region, reached_outside)
+}
+
+fn is_outside(start: Pos, m: &Map) -> bool {
+    let mut curr_y = start.y;
+    let mut seen_tiles = vec![];
+    while curr_y >= 0 {
+        let c = m[Pos::new(start.x, curr_y)];
+
+        seen_tiles.push(c);
+
+        curr_y -= 1;
+    }
+    let mut outside = false;
+
+    let mut first_b = '.';
+    for c in seen_tiles.iter() {
+        match *c {
+            '-' => {
+                outside = !outside;
+            }
+            'S' => {
+                outside = !outside;
+            }
+            '|' => {}
+            'F' => {
+                if first_b == 'J' {
+                    outside = !outside;
+                    first_b = '.';
+                }
+            }
+            '7' => {
+                if first_b == 'L' {
+                    outside = !outside;
+                    first_b = '.';
+                }
+            }
+            'L' => {
+                first_b = 'L';
+            }
+            'J' => {
+                first_b = 'J';
+            }
+            '.' => {}
+
+            _ => panic!("{}", c),
+        }
+    }
+    outside
+}
+
+// Returns length of the loop starting at 'S' and which tiles belong to it.
+fn find_loop(input: &Map) -> (usize, Grid<bool>) {
+    let start: Pos = input.find('S').unwrap();
+
+    let (w, h) = (input.width(), input.height());
+    let mut seen = Grid::new(w, h, vec![false; w * h]);
+    seen[start] = true;
+    let mut p_to_delta: PosToAllowed = vec![smallvec![]; 256];
+    let l: SmallVec<[char; 3]> = smallvec!['-', 'L', 'F'];
+    let r: SmallVec<[char; 3]> = smallvec!['-', 'J', '7'];
+    let u: SmallVec<[char; 3]> = smallvec!['|', '7', 'F'];
+    let d: SmallVec<[char; 3]> = smallvec!['|', 'L', 'J'];
+
+    p_to_delta['|' as usize] = smallvec![(Down, d.clone()), (Up, u.clone())];
+    p_to_delta['-' as usize] = smallvec![(Right, r.clone()), (Left, l.clone())];
+    p_to_delta['L' as usize] = smallvec![(Up, u.clone()), (Right, r.clone())];
+    p_to_delta['J' as usize] = smallvec![(Up, u.clone()), (Left, l.clone())];
+    p_to_delta['7' as usize] = smallvec![(Down, d.clone()), (Left, l.clone())];
+    p_to_delta['F' as usize] = smallvec![(Right, r.clone()), (Down, d.clone())];
+    p_to_delta['S' as usize] = smallvec![(Right, r), (Left, l), (Down, d), (Up, u)];
+
+    let mut len = 0;
+    let mut current = start;
+
+    loop {
+        len += 1;
+        match get_next(current, input, &seen, &p_to_delta) {
+            Some(p) => {
+                seen[p] = true;
+                current = p;
+            }
+            None => {
+                break;
+            }
+        }
+    }
+
+    (len, seen)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let map = Grid::parse_with(input, |b| {
+            ensure!(
+                b"|-LJ7F.S".contains(&b),
+                "unexpected '{}'",
+                b.escape_ascii()
+            );
+            Ok(char::from(b))
+        })?;
+        ensure!(map.find::<i16>('S').is_some(), "no start 'S'");
+        Ok(map)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let (len, _) = find_loop(input);
+        (len / 2).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let (_, seen) = find_loop(input);
+        let mut input = input.clone();
+
+        let mut dots = FxHashSet::default();
+        for p in seen.positions::<i16>() {
+            if !seen[p] {
+                input[p] = '.';
+            }
+            if input[p] == '.' {
+                dots.insert(p);
+            }
+        }
+
+        let mut part2 = 0;
+
+        while let Some(start) = dots.iter().next().copied() {
+            let (region, reached_outside) = flood_fill(start, &input);
+
+            for p in &region {
+                dots.remove(p);
+            }
+
+            if !reached_outside && is_outside(region[0], &input) {
+                part2 += region.len();
+            }
+        }
+
+        part2.into()
+    }
+}
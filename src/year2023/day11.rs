@@ -0,0 +1,99 @@
+use anyhow::Result;
+use rustc_hash::FxHashMap;
+
+type Pos = (i64, i64);
+type Map = Grid<u8>;
+
+use crate::solution::{Answer, Solution};
+use crate::vec::Grid;
+
+fn expand_and_find(m: &Map, mut factor: i64) -> i64 {
+    factor -= 1;
+    let mut galaxies = vec![];
+
+    for (row, cells) in m.rows().enumerate() {
+        for (col, cell) in cells.iter().enumerate() {
+            if *cell != b'.' {
+                galaxies.push((col as i64, row as i64));
+            }
+        }
+    }
+
+    let mut to_expand_by_y = vec![];
+    for (y, cells) in m.rows().enumerate() {
+        if cells.iter().all(|c| *c == b'.') {
+            to_expand_by_y.push(y as i64);
+        }
+    }
+
+    let mut expand_y_by_times: FxHashMap<Pos, i64> = Default::default();
+    for y in &to_expand_by_y {
+        for g in &galaxies {
+            if g.1 > *y {
+                *expand_y_by_times.entry(*g).or_default() += 1;
+            }
+        }
+    }
+
+    for g in &mut galaxies {
+        if let Some(times) = expand_y_by_times.get(g) {
+            g.1 += times * factor;
+        }
+    }
+
+    let mut to_expand_x = vec![];
+    for (x, mut cells) in m.columns().enumerate() {
+        if cells.all(|c| *c == b'.') {
+            to_expand_x.push(x as i64);
+        }
+    }
+
+    let mut expand_x_by_times: FxHashMap<Pos, i64> = Default::default();
+    for x in &to_expand_x {
+        for g in &galaxies {
+            if g.0 > *x {
+                *expand_x_by_times.entry(*g).or_default() += 1;
+            }
+        }
+    }
+
+    for g in &mut galaxies {
+        if let Some(mult) = expand_x_by_times.get(g) {
+            g.0 += mult * factor;
+        }
+    }
+
+    let mut ret = 0;
+    for i in 0..galaxies.len() {
+        for j in (i + 1)..galaxies.len() {
+            if i == j {
+                continue;
+            }
+
+            let dx = (galaxies[i].0 - galaxies[j].0).abs();
+            let dy = (galaxies[i].1 - galaxies[j].1).abs();
+
+            ret += dx + dy;
+        }
+    }
+
+    ret
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::parse_allowed(input, b".#")
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        expand_and_find(input, 2).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        expand_and_find(input, 1000000).into()
+    }
+}
@@ -1,8 +1,8 @@
-use anyhow::Result;
+use anyhow::{ensure, Context, Result};
 use rustc_hash::FxHashMap;
-use std::time::{Duration, Instant};
 
-use crate::input::token_groups;
+use crate::input::try_lines;
+use crate::solution::{Answer, Solution};
 
 fn solve_one(input: &[char], groups: &[i8], ip: usize, ig: usize) -> usize {
     solve_cached(input, groups, ip, ig, &mut Default::default())
@@ -80,7 +80,7 @@ fn solve_aux(
                     }
                 }
             }
-            return 0;
+            0
         }
     }
     if current_i == '.' {
@@ -95,54 +95,56 @@ fn solve_aux(
     unreachable!()
 }
 
-pub fn solve(input: &str, verify_expected: bool, output: bool) -> Result<Duration> {
-    let input: Vec<(Vec<char>, Vec<i8>)> = token_groups(input, "\n", None)
-        .into_iter()
-        .map(|v: Vec<String>| {
-            (
-                v[0].chars().collect(),
-                v[1].split(',').map(|n| n.parse().unwrap()).collect(),
-            )
-        })
-        .collect();
-    let s = Instant::now();
-
-    let part1 = input
-        .iter()
-        .map(|(input, target)| solve_one(&input, &target, 0, 0) as i64)
-        .sum::<i64>();
+pub struct Day12;
 
-    let part2 = input
-        .iter()
-        .map(|(input, target)| {
-            let mut i = input.to_vec();
-            i.push('?');
-            i.extend_from_slice(&input);
-            i.push('?');
-            i.extend_from_slice(&input);
-            i.push('?');
-            i.extend_from_slice(&input);
-            i.push('?');
-            i.extend_from_slice(&input);
+impl Solution for Day12 {
+    type Input = Vec<(Vec<char>, Vec<i8>)>;
 
-            let mut t = target.to_vec();
-            t.extend_from_slice(&target);
-            t.extend_from_slice(&target);
-            t.extend_from_slice(&target);
-            t.extend_from_slice(&target);
-            solve_one(&i, &t, 0, 0) as i64
+    fn parse(input: &str) -> Result<Self::Input> {
+        try_lines(input, |line| {
+            let (springs, groups) = line.split_once(' ').context("missing groups")?;
+            ensure!(
+                springs.chars().all(|c| ".#?".contains(c)),
+                "'{springs}' is not a row of springs"
+            );
+            let groups = groups
+                .split(',')
+                .map(|n| n.parse().with_context(|| format!("cannot parse '{n}'")))
+                .collect::<Result<_>>()?;
+            Ok((springs.chars().collect(), groups))
         })
-        .sum::<i64>();
-
-    let e = s.elapsed();
+    }
 
-    if verify_expected {
-        assert_eq!(7670, part1);
-        assert_eq!(157383940585037, part2);
+    fn part1(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .map(|(input, target)| solve_one(input, target, 0, 0) as i64)
+            .sum::<i64>()
+            .into()
     }
-    if output {
-        println!("\t{}", part1);
-        println!("\t{}", part2);
+
+    fn part2(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .map(|(input, target)| {
+                let mut i = input.to_vec();
+                i.push('?');
+                i.extend_from_slice(input);
+                i.push('?');
+                i.extend_from_slice(input);
+                i.push('?');
+                i.extend_from_slice(input);
+                i.push('?');
+                i.extend_from_slice(input);
+
+                let mut t = target.to_vec();
+                t.extend_from_slice(target);
+                t.extend_from_slice(target);
+                t.extend_from_slice(target);
+                t.extend_from_slice(target);
+                solve_one(&i, &t, 0, 0) as i64
+            })
+            .sum::<i64>()
+            .into()
     }
-    Ok(e)
 }
@@ -0,0 +1,141 @@
+use anyhow::{ensure, Result};
+use itertools::iproduct;
+use rayon::prelude::*;
+use smallvec::{SmallVec, ToSmallVec};
+
+use crate::solution::{Answer, Solution};
+use crate::vec::transpose;
+
+type Row = SmallVec<[u8; 20]>;
+
+fn find_mirror(m: &[Row], not_this: Option<usize>) -> Option<usize> {
+    let mut longest = None;
+    for col in 1..m[0].len() {
+        let mut mirrored_rows = 0;
+        for row in m {
+            let mut left: Row = row[..col].to_smallvec();
+            left.reverse();
+            assert!(!left.is_empty());
+
+            let right = &row[col..];
+            assert!(!right.is_empty());
+
+            let mut any = false;
+            if left.len() >= right.len() {
+                if left.starts_with(right) {
+                    mirrored_rows += 1;
+                    any = true;
+                }
+            } else {
+                if right.starts_with(&left) {
+                    mirrored_rows += 1;
+                    any = true;
+                }
+            }
+            if !any {
+                break;
+            }
+        }
+        if mirrored_rows == m.len() && not_this != Some(col) {
+            longest = Some(col);
+        }
+    }
+
+    longest
+}
+
+fn flip(m: &mut [Row], row: usize, col: usize) {
+    let v = m[row][col];
+    let new = if v == b'.' { b'#' } else { b'.' };
+    m[row][col] = new;
+}
+
+fn find_smudge(
+    map: &[Row],
+    old_by_col: Option<usize>,
+    old_by_row: Option<usize>,
+) -> (Option<usize>, Option<usize>) {
+    let mut copy = map.to_vec();
+    for (row, col) in iproduct!(0..map.len(), 0..map[0].len()) {
+        flip(&mut copy, row, col);
+
+        let by_col = find_mirror(&copy, old_by_col);
+        let by_row = find_mirror(&transpose(&copy), old_by_row);
+
+        match (by_col, by_row) {
+            (None, r @ Some(_)) => return (None, r),
+            (c @ Some(_), None) => return (c, None),
+            _ => {}
+        }
+        flip(&mut copy, row, col);
+    }
+    unreachable!()
+}
+
+fn summarize(results: &[(Option<usize>, Option<usize>)]) -> usize {
+    results
+        .iter()
+        .flat_map(|(_, by_row)| *by_row)
+        .sum::<usize>()
+        * 100
+        + results
+            .iter()
+            .flat_map(|(by_col, _)| *by_col)
+            .sum::<usize>()
+}
+
+fn mirrors(input: &[Vec<Row>]) -> Vec<(Option<usize>, Option<usize>)> {
+    input
+        .iter()
+        .map(|map| (find_mirror(map, None), find_mirror(&transpose(map), None)))
+        .collect()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Vec<Row>>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut maps: Vec<Vec<Row>> = vec![vec![]];
+        for (i, line) in input.lines().enumerate() {
+            let map = maps.last_mut().unwrap();
+            if line.is_empty() {
+                if !map.is_empty() {
+                    maps.push(vec![]);
+                }
+                continue;
+            }
+            ensure!(
+                line.bytes().all(|b| b == b'.' || b == b'#'),
+                "line {}: '{line}' is not made of '.' and '#'",
+                i + 1
+            );
+            if let Some(first) = map.first() {
+                ensure!(
+                    line.len() == first.len(),
+                    "line {}: {} cells instead of {}",
+                    i + 1,
+                    line.len(),
+                    first.len()
+                );
+            }
+            map.push(line.bytes().collect());
+        }
+        maps.retain(|map| !map.is_empty());
+        Ok(maps)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        summarize(&mirrors(input)).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let results: Vec<(Option<usize>, Option<usize>)> = input
+            .par_iter()
+            .zip(mirrors(input))
+            .map(|(map, (by_col, by_row))| find_smudge(map, by_col, by_row))
+            .collect();
+        summarize(&results).into()
+    }
+}
@@ -0,0 +1,93 @@
+use anyhow::Result;
+use itertools::iproduct;
+use rustc_hash::FxHashMap;
+
+use crate::solution::{Answer, Solution};
+use crate::vec::Grid;
+
+type Pos = crate::pos::Pos<i64>;
+type Map = Grid<u8>;
+
+fn move_by(m: &mut Map, start: Pos, dir: Pos) {
+    let mut curr = start;
+    debug_assert!(m[curr] == b'O');
+    while let Some(&at_next) = m.get(curr + dir) {
+        if at_next == b'#' || at_next == b'O' {
+            break;
+        }
+        curr = curr + dir;
+    }
+    m[start] = b'.';
+    m[curr] = b'O';
+}
+
+fn move_rocks(
+    m: &mut Map,
+    dir: Pos,
+    rows: impl Iterator<Item = usize>,
+    cols: impl Iterator<Item = usize> + Clone,
+) {
+    for (row, col) in iproduct!(rows, cols) {
+        let pos = Pos::new(col as i64, row as i64);
+        if m[pos] == b'O' {
+            move_by(m, pos, dir);
+        }
+    }
+}
+
+fn total_load(m: &Map) -> usize {
+    m.positions::<i64>()
+        .filter(|p| m[*p] == b'O')
+        .map(|p| m.height() - p.row() as usize)
+        .sum()
+}
+
+fn cycle_map(map: &mut Map) {
+    let l = map.height();
+    let ll = map.width();
+    move_rocks(map, Pos::new(0, -1), 0..l, 0..ll);
+    move_rocks(map, Pos::new(-1, 0), 0..l, 0..ll);
+    move_rocks(map, Pos::new(0, 1), (0..l).rev(), (0..ll).rev());
+    move_rocks(map, Pos::new(1, 0), (0..l).rev(), (0..ll).rev());
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::parse_allowed(input, b".#O")
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let mut map = input.clone();
+        let l = map.height();
+        let ll = map.width();
+        move_rocks(&mut map, Pos::new(0, -1), 0..l, 0..ll);
+        total_load(&map).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let mut seen: FxHashMap<Map, usize> = Default::default();
+
+        let mut map = input.clone();
+        let total = 1000000000;
+        for cycle in 0..total {
+            if let Some(previous_cycle) = seen.get(&map) {
+                let left = total - cycle;
+                let cycle_len = cycle - previous_cycle;
+                let times = left / cycle_len;
+                let todo = left - (times * cycle_len);
+                for _ in 0..todo {
+                    cycle_map(&mut map);
+                }
+
+                break;
+            }
+            seen.insert(map.clone(), cycle);
+            cycle_map(&mut map);
+        }
+        total_load(&map).into()
+    }
+}
@@ -0,0 +1,108 @@
+use anyhow::{ensure, Context, Result};
+use smallvec::{smallvec, SmallVec};
+use smol_str::SmolStr;
+use std::str::FromStr;
+
+use crate::input::try_tokens;
+use crate::solution::{Answer, Solution};
+
+fn hash(s: &str) -> i64 {
+    let mut ret = 0i64;
+    for c in s.chars() {
+        ret += (c as u8) as i64;
+        ret *= 17;
+        ret %= 256;
+    }
+
+    ret
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Remove,
+    /// Puts a lens of this focal length into the box.
+    Insert(u8),
+}
+
+/// Step of the initialization sequence, like `rn=1` or `cm-`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    text: SmolStr,
+    label: SmolStr,
+    operation: Operation,
+}
+
+impl FromStr for Step {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (label, operation) = match s.strip_suffix('-') {
+            Some(label) => (label, Operation::Remove),
+            None => {
+                let (label, focal) = s.split_once('=').context("expected '-' or '=N'")?;
+                let focal = focal
+                    .parse()
+                    .ok()
+                    .filter(|f| (1..=9).contains(f))
+                    .with_context(|| format!("focal length '{focal}' is not 1-9"))?;
+                (label, Operation::Insert(focal))
+            }
+        };
+        ensure!(
+            !label.is_empty() && label.chars().all(|c| c.is_ascii_lowercase()),
+            "label '{label}' is not lowercase letters"
+        );
+        Ok(Self {
+            text: s.into(),
+            label: label.into(),
+            operation,
+        })
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<Step>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        try_tokens(input.trim_end(), Some(","))
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input.iter().map(|s| hash(&s.text)).sum::<i64>().into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let mut boxes: Vec<SmallVec<[(SmolStr, u8); 8]>> = vec![smallvec![]; 256];
+        for step in input {
+            let label = &step.label;
+            let h = hash(label);
+
+            match step.operation {
+                Operation::Remove => {
+                    if let Some(idx) = boxes[h as usize].iter().position(|(l, _)| l == label) {
+                        boxes[h as usize].remove(idx);
+                    }
+                }
+                Operation::Insert(focal) => {
+                    if let Some(idx) = boxes[h as usize].iter().position(|(l, _)| l == label) {
+                        boxes[h as usize][idx].1 = focal;
+                    } else {
+                        boxes[h as usize].push((label.clone(), focal));
+                    }
+                }
+            }
+        }
+
+        let mut part2 = 0;
+        for (box_id, b) in boxes.iter().enumerate() {
+            for (f_id, (_label, f)) in b.iter().enumerate() {
+                let power = (box_id + 1) * (f_id + 1) * *f as usize;
+
+                part2 += power;
+            }
+        }
+        part2.into()
+    }
+}
@@ -0,0 +1,85 @@
+use anyhow::Result;
+use rayon::prelude::*;
+use rustc_hash::FxHashSet;
+use std::mem::swap;
+
+use crate::solution::{Answer, Solution};
+use crate::vec::Grid;
+
+use crate::pos::Dir4;
+
+type Pos = crate::pos::Pos<i64>;
+
+fn energized(input: &Grid<u8>, start: Pos, dir: Dir4) -> usize {
+    let mut beams: Vec<(Pos, Dir4)> = vec![(start, dir)];
+    let mut seen_beams: FxHashSet<(Pos, Dir4)> = Default::default();
+
+    let mut next_beams: Vec<(Pos, Dir4)> = vec![];
+    while !beams.is_empty() {
+        next_beams.clear();
+        for (beam, dir) in &mut beams {
+            if !seen_beams.insert((*beam, *dir)) {
+                continue;
+            }
+            let next_pos = *beam + dir.delta();
+            if let Some(&next) = input.get(next_pos) {
+                match next {
+                    b'.' => {
+                        next_beams.push((next_pos, *dir));
+                    }
+                    b'|' | b'-' if dir.is_horizontal() == (next == b'-') => {
+                        next_beams.push((next_pos, *dir));
+                    }
+                    b'|' | b'-' => {
+                        next_beams.push((next_pos, dir.turn_left()));
+                        next_beams.push((next_pos, dir.turn_right()));
+                    }
+                    b'/' if dir.is_horizontal() => {
+                        next_beams.push((next_pos, dir.turn_left()));
+                    }
+                    b'\\' if !dir.is_horizontal() => {
+                        next_beams.push((next_pos, dir.turn_left()));
+                    }
+                    b'/' | b'\\' => {
+                        next_beams.push((next_pos, dir.turn_right()));
+                    }
+                    c => unreachable!("'{}' passed parse", c as char),
+                }
+            }
+        }
+        swap(&mut beams, &mut next_beams);
+    }
+
+    seen_beams
+        .iter()
+        .map(|(p, _)| p)
+        .collect::<FxHashSet<_>>()
+        .len()
+        - 1
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::parse_allowed(input, b"./\\|-")
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        energized(input, Pos::new(-1, 0), Dir4::Right).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let (w, h) = (input.width() as i64, input.height() as i64);
+        (0..h)
+            .flat_map(|y| [(Pos::new(-1, y), Dir4::Right), (Pos::new(w, y), Dir4::Left)])
+            .chain((0..w).flat_map(|x| [(Pos::new(x, -1), Dir4::Down), (Pos::new(x, h), Dir4::Up)]))
+            .par_bridge()
+            .map(|(start, dir)| energized(input, start, dir))
+            .max()
+            .unwrap()
+            .into()
+    }
+}
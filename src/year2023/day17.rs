@@ -0,0 +1,67 @@
+use anyhow::{ensure, Result};
+use smallvec::{smallvec, SmallVec};
+use std::ops::Range;
+
+use crate::solution::{Answer, Solution};
+use crate::{dijkstra::dijkstra_dial_to, vec::Grid};
+
+use crate::pos::Dir4;
+
+type Pos = crate::pos::Pos<i16>;
+
+fn find_best_path(input: &Grid<usize>, move_range: Range<i8>) -> usize {
+    let neighbours = |(curr_pos, curr_dir): &(Pos, Dir4)| -> SmallVec<[((Pos, Dir4), usize); 14]> {
+        let mut ret: SmallVec<[_; 14]> = smallvec![];
+        for next_dir in [curr_dir.turn_left(), curr_dir.turn_right()] {
+            let step = next_dir.delta();
+            for dist in move_range.clone() {
+                let next_pos = *curr_pos + step * dist;
+                if input.contains(next_pos) {
+                    let loss = (1..=dist).map(|d| input[*curr_pos + step * d]).sum();
+                    ret.push(((next_pos, next_dir), loss));
+                }
+            }
+        }
+        ret
+    };
+
+    let w = input.width() as i16;
+    let end = Pos::new(w - 1, input.height() as i16 - 1);
+    let (loss, _) = dijkstra_dial_to(
+        &[(Pos::new(0, 0), Dir4::Down), (Pos::new(0, 0), Dir4::Right)],
+        input.cells().len() * 4,
+        9 * move_range.end as usize,
+        |(pos, dir)| pos.idx_1d(w) * 4 + *dir as usize,
+        |(pos, _)| *pos == end,
+        neighbours,
+    )
+    .unwrap();
+    loss
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Grid<usize>;
+
+    const PARALLEL_PARTS: bool = true;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::parse_with(input, |b| {
+            ensure!(
+                (b'1'..=b'9').contains(&b),
+                "unexpected '{}'",
+                b.escape_ascii()
+            );
+            Ok((b - b'0') as usize)
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        find_best_path(input, 1..4).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        find_best_path(input, 4..11).into()
+    }
+}
@@ -1,67 +1,31 @@
 use anyhow::Result;
 use itertools::Itertools;
-use std::{
-    str::FromStr,
-    time::{Duration, Instant},
-};
 
-use crate::input::tokens;
+use crate::input::parse_lines;
+use crate::pos::Dir4;
+use crate::solution::{Answer, Solution};
 
 type Pos = crate::pos::Pos<i32>;
-type Dir2d = crate::pos::Pos<i8>;
-
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
-enum Dir {
-    U,
-    D,
-    R,
-    L,
-}
-
-impl From<Dir> for Dir2d {
-    fn from(value: Dir) -> Self {
-        match value {
-            Dir::U => Dir2d::new(0, -1),
-            Dir::D => Dir2d::new(0, 1),
-            Dir::R => Dir2d::new(1, 0),
-            Dir::L => Dir2d::new(-1, 0),
-        }
-    }
-}
-
-impl FromStr for Dir {
-    type Err = ();
-
-    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        Ok(match s.chars().next() {
-            Some('U') => Self::U,
-            Some('D') => Self::D,
-            Some('L') => Self::L,
-            Some('R') => Self::R,
-            c => todo!("c: '{c:?}"),
-        })
-    }
-}
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
-struct Op {
-    dir: Dir,
+pub struct Op {
+    dir: Dir4,
     dist: i32,
     color: String,
 }
 
 impl Op {
-    fn real_color(&self) -> (i32, Dir2d) {
+    fn real_color(&self) -> (i32, Dir4) {
         let dist: String = self.color.chars().take(5).collect();
         let dist = i32::from_str_radix(&dist, 16).unwrap();
         let dir = match self.color.chars().last().unwrap() {
-            '0' => Dir::R,
-            '1' => Dir::D,
-            '2' => Dir::L,
-            '3' => Dir::U,
+            '0' => Dir4::Right,
+            '1' => Dir4::Down,
+            '2' => Dir4::Left,
+            '3' => Dir4::Up,
             c => todo!("c: {c}"),
         };
-        (dist, dir.into())
+        (dist, dir)
     }
 }
 
@@ -84,7 +48,7 @@ fn border_len(corners: &[Pos]) -> i64 {
 }
 
 fn picks_theorem(corners: &[Pos]) -> i64 {
-    shoelace_formula(corners) + border_len(&corners) / 2 + 1
+    shoelace_formula(corners) + border_len(corners) / 2 + 1
 }
 
 fn find_corners(input: &[Op], part2: bool) -> Vec<Pos> {
@@ -92,12 +56,13 @@ fn find_corners(input: &[Op], part2: bool) -> Vec<Pos> {
     let mut corners: Vec<Pos> = Default::default();
 
     for op in input.iter() {
-        let (dist, dir): (i32, Dir2d) = if !part2 {
-            (op.dist, op.dir.into())
+        let (dist, dir) = if !part2 {
+            (op.dist, op.dir)
         } else {
             op.real_color()
         };
 
+        let dir = dir.delta();
         curr = curr + Pos::new(dir.x as i32, dir.y as i32) * dist;
         corners.push(curr);
     }
@@ -105,37 +70,25 @@ fn find_corners(input: &[Op], part2: bool) -> Vec<Pos> {
     corners
 }
 
-pub fn solve(input: &str, verify_expected: bool, output: bool) -> Result<Duration> {
-    let input: Vec<Op> = tokens(input, None)
-        .chunks(3)
-        .map(|chunk: &[String]| {
-            let dir = chunk[0].parse().unwrap();
-            let dist = chunk[1].parse().unwrap();
-            let l = chunk[2].len();
-            let color: String = chunk[2].chars().skip(2).take(l - 3).collect();
-
-            Op { dir, dist, color }
-        })
-        .collect();
-
-    let s = Instant::now();
+pub struct Day18;
 
-    let corners = find_corners(&input, false);
-    let part1 = picks_theorem(&corners);
-    let corners = find_corners(&input, true);
-    let part2 = picks_theorem(&corners);
+impl Solution for Day18 {
+    type Input = Vec<Op>;
 
-    let e = s.elapsed();
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_lines(input, "{} {} (#{})")?
+            .into_iter()
+            .map(|(dir, dist, color)| Op { dir, dist, color })
+            .collect())
+    }
 
-    if verify_expected {
-        assert_eq!(47527, part1);
-        assert_eq!(52240187443190, part2);
+    fn part1(input: &Self::Input) -> Answer {
+        picks_theorem(&find_corners(input, false)).into()
     }
-    if output {
-        println!("\t{}", part1);
-        println!("\t{}", part2);
+
+    fn part2(input: &Self::Input) -> Answer {
+        picks_theorem(&find_corners(input, true)).into()
     }
-    Ok(e)
 }
 
 #[test]
@@ -0,0 +1,328 @@
+use anyhow::{bail, ensure, Context, Result};
+use rustc_hash::FxHashMap;
+use std::{fmt::Debug, str::FromStr};
+
+use crate::input::{parse_lines, try_lines, Pattern};
+use crate::solution::{Answer, Solution};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct Part {
+    x: i64,
+    m: i64,
+    a: i64,
+    s: i64,
+}
+
+impl Part {
+    fn sum(&self) -> i64 {
+        self.x + self.m + self.a + self.s
+    }
+
+    fn is_accepted(&self, rules: &FxHashMap<String, Vec<Rule>>) -> bool {
+        let mut curr = "in".to_owned();
+        loop {
+            // println!("curr: {curr}");
+            let rules = rules.get(&curr).unwrap();
+            for rule in rules {
+                match rule.eval(self) {
+                    Res::Accept => return true,
+                    Res::Reject => return false,
+                    Res::Continue => {}
+                    Res::Jump(target) => {
+                        curr = target;
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone)]
+struct Relation {
+    name: char,
+    op: char,
+    constant: i64,
+    negation: bool,
+}
+impl Relation {
+    fn apply(&self, r: &mut PossibleRange) {
+        let range: &mut (i64, i64) = match self.name {
+            'x' => &mut r.x,
+            'm' => &mut r.m,
+            'a' => &mut r.a,
+            's' => &mut r.s,
+            _ => todo!(),
+        };
+        match (self.op, self.negation) {
+            ('<', true) => {
+                range.0 = self.constant.max(range.0);
+            }
+            ('<', false) => {
+                range.1 = (self.constant - 1).min(range.1);
+            }
+            ('>', true) => {
+                range.1 = self.constant.min(range.1);
+            }
+            ('>', false) => {
+                range.0 = (self.constant + 1).max(range.0);
+            }
+            other => todo!("other: {other:?}"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+pub enum Rule {
+    Relation {
+        name: char,
+        op: char,
+        constant: i64,
+        target: String,
+    },
+    Jump(String),
+    Accept,
+    Reject,
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+enum Res {
+    Accept,
+    Reject,
+    Continue,
+    Jump(String),
+}
+
+impl Rule {
+    fn eval(&self, part: &Part) -> Res {
+        match self {
+            Self::Accept => Res::Accept,
+            Self::Reject => Res::Reject,
+            Self::Relation {
+                name,
+                op,
+                constant,
+                target,
+            } => {
+                let val = match name {
+                    'x' => part.x,
+                    'm' => part.m,
+                    'a' => part.a,
+                    's' => part.s,
+                    other => todo!("name: {other}"),
+                };
+                let result = if *op == '<' {
+                    val < *constant
+                } else {
+                    val > *constant
+                };
+                if !result {
+                    Res::Continue
+                } else if target == "A" {
+                    Res::Accept
+                } else if target == "R" {
+                    Res::Reject
+                } else {
+                    Res::Jump(target.clone())
+                }
+            }
+            Rule::Jump(target) => Res::Jump(target.clone()),
+        }
+    }
+}
+
+impl FromStr for Rule {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        if let Some((expr, target)) = s.split_once(':') {
+            let op = expr
+                .chars()
+                .find(|c| *c == '<' || *c == '>')
+                .with_context(|| format!("missing '<' or '>' in '{s}'"))?;
+            let (name, constant) = expr.split_once(op).unwrap();
+            ensure!(
+                ["x", "m", "a", "s"].contains(&name),
+                "unknown category '{name}' in '{s}'"
+            );
+            let constant: i64 = constant
+                .parse()
+                .with_context(|| format!("cannot parse '{constant}' in '{s}'"))?;
+            return Ok(Self::Relation {
+                name: name.chars().next().unwrap(),
+                op,
+                constant,
+                target: target.to_owned(),
+            });
+        } else if s == "A" {
+            return Ok(Self::Accept);
+        } else if s == "R" {
+            return Ok(Self::Reject);
+        } else if !s.is_empty() && s.chars().all(|c| c.is_lowercase()) {
+            return Ok(Self::Jump(s.to_owned()));
+        }
+        bail!("invalid rule '{s}'")
+    }
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone)]
+struct Location {
+    rule: String,
+    next_offset: usize,
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+struct PossibleRange {
+    x: (i64, i64),
+    m: (i64, i64),
+    a: (i64, i64),
+    s: (i64, i64),
+}
+
+impl PossibleRange {
+    fn new() -> Self {
+        Self {
+            x: (1, 4000),
+            m: (1, 4000),
+            a: (1, 4000),
+            s: (1, 4000),
+        }
+    }
+
+    fn size(&self) -> i64 {
+        let x = self.x.1 - self.x.0 + 1;
+        let m = self.m.1 - self.m.0 + 1;
+        let a = self.a.1 - self.a.0 + 1;
+        let s = self.s.1 - self.s.0 + 1;
+        x * m * a * s
+    }
+}
+
+pub struct System {
+    rules: FxHashMap<String, Vec<Rule>>,
+    parts: Vec<Part>,
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = System;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (workflows, parts) = input.split_once("\n\n").context("missing parts")?;
+
+        let parts: Vec<Part> = parse_lines(parts, "{{x={},m={},a={},s={}}}")
+            .context("parts")?
+            .into_iter()
+            .map(|(x, m, a, s)| Part { x, m, a, s })
+            .collect();
+        let mut rules: FxHashMap<String, Vec<Rule>> = Default::default();
+        let workflow = Pattern::new("{}{{{}}}")?;
+        for (name, line_rules) in try_lines(workflows, |line| {
+            let (name, line_rules): (String, String) = workflow.parse(line)?;
+            let line_rules = line_rules
+                .split(',')
+                .map(|s| s.parse())
+                .collect::<Result<Vec<Rule>>>()?;
+            Ok((name, line_rules))
+        })? {
+            rules.insert(name, line_rules);
+        }
+
+        Ok(System { rules, parts })
+    }
+
+    fn part1(System { rules, parts }: &Self::Input) -> Answer {
+        parts
+            .iter()
+            .filter(|part| part.is_accepted(rules))
+            .map(|part| part.sum())
+            .sum::<i64>()
+            .into()
+    }
+
+    fn part2(System { rules, .. }: &Self::Input) -> Answer {
+        let mut states: Vec<(Location, Vec<Relation>)> = vec![];
+        let mut accepted: Vec<Vec<Relation>> = vec![];
+
+        states.push((
+            Location {
+                rule: "in".to_owned(),
+                next_offset: 0,
+            },
+            vec![],
+        ));
+
+        while !states.is_empty() {
+            let mut new_states: Vec<(Location, Vec<Relation>)> = vec![];
+
+            for state in &states {
+                match &rules.get(&state.0.rule).unwrap()[state.0.next_offset] {
+                    Rule::Relation {
+                        name,
+                        op,
+                        constant,
+                        target,
+                    } => {
+                        let mut rule = Relation {
+                            name: *name,
+                            op: *op,
+                            constant: *constant,
+                            negation: false,
+                        };
+                        let mut s = state.1.clone();
+                        s.push(rule.clone());
+                        if target == "A" {
+                            accepted.push(s);
+                        } else if target != "R" {
+                            new_states.push((
+                                Location {
+                                    rule: target.clone(),
+                                    next_offset: 0,
+                                },
+                                s,
+                            ));
+                        }
+
+                        rule.negation = true;
+                        let mut s = state.1.clone();
+                        s.push(rule.clone());
+                        new_states.push((
+                            Location {
+                                rule: state.0.rule.clone(),
+                                next_offset: state.0.next_offset + 1,
+                            },
+                            s,
+                        ));
+                    }
+                    Rule::Jump(target) => {
+                        new_states.push((
+                            Location {
+                                rule: target.clone(),
+                                next_offset: 0,
+                            },
+                            state.1.clone(),
+                        ));
+                    }
+                    Rule::Accept => {
+                        accepted.push(state.1.clone());
+                    }
+                    Rule::Reject => {}
+                }
+            }
+
+            states = new_states;
+        }
+
+        let mut part2 = 0i64;
+        for rules in &accepted {
+            let mut r = PossibleRange::new();
+            for rule in rules {
+                rule.apply(&mut r);
+            }
+            part2 += r.size();
+        }
+
+        part2.into()
+    }
+}
@@ -0,0 +1,260 @@
+use anyhow::{bail, ensure, Result};
+use rustc_hash::FxHashMap;
+use smol_str::SmolStr;
+use std::collections::VecDeque;
+
+use crate::input::{try_lines, Pattern};
+use crate::solution::{Answer, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Pulse {
+    High,
+    Low,
+}
+
+#[derive(Debug, Clone)]
+pub enum Module {
+    FlipFlop {
+        on: bool,
+        outputs: Vec<SmolStr>,
+    },
+    Conjunction {
+        last_input_pulse: FxHashMap<SmolStr, Pulse>,
+        outputs: Vec<SmolStr>,
+    },
+    Broadcast {
+        outputs: Vec<SmolStr>,
+    },
+    Output {
+        low_received: bool,
+    },
+}
+
+impl Module {
+    fn add_input_name(&mut self, name: &str) {
+        if let Self::Conjunction {
+            last_input_pulse, ..
+        } = self
+        {
+            last_input_pulse.insert(name.into(), Pulse::Low);
+        }
+    }
+
+    fn outputs(&self) -> &[SmolStr] {
+        match self {
+            Module::FlipFlop { outputs, .. } => outputs,
+            Module::Conjunction { outputs, .. } => outputs,
+            Module::Broadcast { outputs } => outputs,
+            Module::Output { .. } => &[],
+        }
+    }
+
+    fn process(&mut self, from: &str, pulse: Pulse) -> Vec<(SmolStr, Pulse)> {
+        let mut ret = vec![];
+        match self {
+            Module::FlipFlop { on, outputs } => {
+                if pulse == Pulse::Low {
+                    let pulse = if *on { Pulse::Low } else { Pulse::High };
+                    for output in outputs {
+                        ret.push((output.to_owned(), pulse));
+                    }
+                    *on = !*on;
+                }
+            }
+            Module::Conjunction {
+                last_input_pulse,
+                outputs,
+            } => {
+                *last_input_pulse.get_mut(from).unwrap() = pulse;
+                let pulse = if last_input_pulse.values().all(|v| *v == Pulse::High) {
+                    Pulse::Low
+                } else {
+                    Pulse::High
+                };
+                for output in outputs {
+                    ret.push((output.to_owned(), pulse));
+                }
+            }
+            Module::Broadcast { outputs } => {
+                for output in outputs {
+                    ret.push((output.to_owned(), pulse));
+                }
+            }
+            Module::Output { low_received } => {
+                if pulse == Pulse::Low {
+                    *low_received = true;
+                }
+            }
+        }
+        ret
+    }
+}
+
+type Topology = FxHashMap<SmolStr, Vec<SmolStr>>;
+type Kinds = FxHashMap<SmolStr, Module>;
+
+fn send_pulse(kinds: &mut Kinds) -> (i64, i64) {
+    let mut low = 0i64;
+    let mut high = 0i64;
+    let mut todo: VecDeque<(SmolStr, Pulse, SmolStr)> = VecDeque::default();
+    todo.push_back(("broadcaster".into(), Pulse::Low, "button".into()));
+
+    while let Some((module_name, pulse, from)) = todo.pop_front() {
+        match pulse {
+            Pulse::High => high += 1,
+            Pulse::Low => low += 1,
+        }
+        let next_signals = kinds.get_mut(&module_name).unwrap().process(&from, pulse);
+        for next in next_signals {
+            todo.push_back((next.0, next.1, module_name.clone()));
+        }
+    }
+    (low, high)
+}
+
+fn turned_on(kinds: &Kinds, names: &[&str]) -> usize {
+    let mut ret = 0;
+    for name in names {
+        if let Some(Module::FlipFlop { on: true, .. }) = kinds.get(*name) {
+            ret += 1;
+        }
+    }
+    ret
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Kinds;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let pattern = Pattern::new("{} -> {}")?;
+        let modules = try_lines(input, |line| {
+            let (name, outputs): (String, String) = pattern.parse(line)?;
+            let outputs: Vec<SmolStr> = outputs.split(", ").map(SmolStr::from).collect();
+            Ok(if name == "broadcaster" {
+                (name.into(), Module::Broadcast { outputs })
+            } else if let Some(name) = name.strip_prefix('%') {
+                (name.into(), Module::FlipFlop { on: false, outputs })
+            } else if let Some(name) = name.strip_prefix('&') {
+                let last_input_pulse = FxHashMap::default();
+                (
+                    name.into(),
+                    Module::Conjunction {
+                        last_input_pulse,
+                        outputs,
+                    },
+                )
+            } else {
+                bail!("module '{name}' is neither broadcaster, '%' nor '&'")
+            })
+        })?;
+
+        // All outputs but a single sink, like `rx`, are modules.
+        let lines = input.lines().enumerate().filter(|(_, l)| !l.is_empty());
+        let mut sink = None;
+        for ((i, _), (_, module)) in lines.zip(&modules) {
+            for output in module.outputs() {
+                if !modules.iter().any(|(name, _)| name == output) {
+                    let sink = sink.get_or_insert(output);
+                    ensure!(
+                        sink == &output,
+                        "line {}: '{output}' is not a module, and neither is '{sink}'",
+                        i + 1
+                    );
+                }
+            }
+        }
+
+        let mut topology = Topology::default();
+        let mut kinds: Kinds = modules.into_iter().collect();
+        ensure!(kinds.contains_key("broadcaster"), "no broadcaster module");
+
+        for (name, module) in &kinds {
+            for output in module.outputs() {
+                topology
+                    .entry(name.to_owned())
+                    .or_default()
+                    .push(output.to_owned());
+            }
+        }
+
+        let mut all2: Vec<SmolStr> = vec![];
+        let mut todo: VecDeque<SmolStr> = VecDeque::new();
+        todo.push_back("broadcaster".into());
+        while let Some(next) = todo.pop_front() {
+            if all2.contains(&next) {
+                continue;
+            }
+            all2.push(next.clone());
+            if kinds.contains_key(&next) {
+                for n in kinds.get(&next).unwrap().outputs() {
+                    todo.push_back(n.clone());
+                }
+            }
+        }
+
+        for (name, outputs) in topology {
+            for output in outputs {
+                kinds
+                    .entry(output)
+                    .or_insert(Module::Output {
+                        low_received: false,
+                    })
+                    .add_input_name(&name);
+            }
+        }
+
+        Ok(kinds)
+    }
+
+    fn part1(kinds: &Self::Input) -> Answer {
+        let mut kinds = kinds.clone();
+        let mut low = 0i64;
+        let mut high = 0i64;
+
+        for _ in 0..1000 {
+            let (l, h) = send_pulse(&mut kinds);
+            low += l;
+            high += h;
+        }
+
+        (low * high).into()
+    }
+
+    fn part2(kinds: &Self::Input) -> Answer {
+        // Thank you graphiz!
+        let g1 = [
+            "bv", "ct", "fk", "qc", "dj", "ts", "bs", "vg", "tc", "jz", "jb", "bf",
+        ];
+        let g2 = [
+            "hv", "kr", "rg", "zn", "mm", "ms", "zl", "hh", "np", "xb", "ds", "kz",
+        ];
+        let g3 = [
+            "kc", "kf", "dh", "bm", "vf", "zk", "rp", "gq", "dp", "cc", "jk", "vh",
+        ];
+        let g4 = [
+            "fm", "dx", "tx", "cm", "hl", "gr", "ns", "db", "zz", "px", "sn", "jd",
+        ];
+
+        let mut kinds = kinds.clone();
+        let mut todo = vec![g1, g2, g3, g4];
+        let mut part2 = 1;
+        for i in 1i64.. {
+            if todo.is_empty() {
+                break;
+            }
+            send_pulse(&mut kinds);
+
+            for idx in 0..todo.len() {
+                if turned_on(&kinds, &todo[idx]) == 0 && i > 1 {
+                    part2 *= i;
+                    todo.remove(idx);
+                    break;
+                }
+            }
+        }
+
+        part2.into()
+    }
+}
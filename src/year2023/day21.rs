@@ -0,0 +1,105 @@
+use anyhow::{ensure, Result};
+use smallvec::{smallvec, SmallVec};
+
+type Pos = crate::pos::Pos<i16>;
+
+use crate::dijkstra::bfs_layers;
+use crate::pos::Dir4;
+use crate::solution::{Answer, Params, Solution};
+use crate::vec::Grid;
+
+fn real_coord(val: i16, len: i16) -> i16 {
+    if val >= 0 {
+        val % len
+    } else {
+        (len + val % len) % len
+    }
+}
+
+fn real_pos(pos: Pos, w: i16, h: i16) -> Pos {
+    let x = real_coord(pos.x, w);
+    let y = real_coord(pos.y, h);
+    Pos::new(x, y)
+}
+
+// Neighbours of `pos` on the map repeated infinitely in every direction.
+fn neighbours(input: &Grid<u8>, pos: Pos) -> SmallVec<[(Pos, u8); 4]> {
+    let (w, h) = (input.width() as i16, input.height() as i16);
+    let mut ret = smallvec![];
+    for dir in Dir4::ALL {
+        let p = pos + dir.delta();
+        ret.push((p, input[real_pos(p, w, h)]));
+    }
+
+    ret
+}
+
+// Number of tiles on which one can end after exactly each of `steps_to_capture`
+// steps. A tile first reached after `k` steps can be returned to after every
+// further two steps, so it counts for the steps of the same parity as `k`.
+fn travel(input: &Grid<u8>, start: &[Pos], steps_to_capture: &[usize]) -> Vec<i64> {
+    let mut sizes = vec![];
+    let mut by_parity = [0; 2];
+    let steps = *steps_to_capture.last().unwrap() + 1;
+    let layers = bfs_layers(start, |p| {
+        neighbours(input, *p)
+            .into_iter()
+            .filter(|(_, val)| *val != b'#')
+            .map(|(p, _)| p)
+    });
+    for (i, layer) in layers.take(steps).enumerate() {
+        by_parity[i % 2] += layer.len() as i64;
+        if steps_to_capture.contains(&i) {
+            sizes.push(by_parity[i % 2]);
+        }
+    }
+    sizes
+}
+
+pub struct Garden {
+    map: Grid<u8>,
+    /// Steps taken in part 1.
+    steps: usize,
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Garden;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input> {
+        let map = Grid::parse_allowed(input, b".#S")?;
+        ensure!(map.find::<i16>(b'S').is_some(), "no start 'S'");
+        Ok(Garden {
+            map,
+            steps: params.get("steps", 64)?,
+        })
+    }
+
+    fn part1(Garden { map, steps }: &Self::Input) -> Answer {
+        let start = map.find(b'S').unwrap();
+        travel(map, &[start], &[*steps])[0].into()
+    }
+
+    fn part2(Garden { map: input, .. }: &Self::Input) -> Answer {
+        let start = input.find(b'S').unwrap();
+        let steps_to_capture = [65, 65 + 131, 65 + 131 * 2];
+        let sizes = travel(input, &[start], &steps_to_capture);
+        let ys = [sizes[0] as f64, sizes[1] as f64, sizes[2] as f64];
+
+        let params: Vec<i64> = polyfit_rs::polyfit_rs::polyfit(&[0f64, 1f64, 2f64], &ys, 2)
+            .unwrap()
+            .iter()
+            .map(|v| v.round() as i64)
+            .collect();
+
+        let target = 26501365;
+        assert_eq!(0, (target - 65) % 131);
+        let target = (target - 65) / 131;
+        (params[2] * target * target + params[1] * target + params[0]).into()
+    }
+}
@@ -0,0 +1,149 @@
+use anyhow::Result;
+use rayon::prelude::*;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::input::parse_lines;
+use crate::pos::Pos3;
+use crate::solution::{Answer, Solution};
+
+type Pos = Pos3<i16>;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Brick {
+    id: usize,
+    points: FxHashSet<Pos>,
+    min: Pos,
+}
+
+impl Brick {
+    fn new(a: Pos, b: Pos, id: usize) -> Self {
+        let points = Pos3::cuboid(a, b).collect();
+        Self {
+            id,
+            points,
+            min: a.min_each(b),
+        }
+    }
+
+    fn on_ground(&self) -> bool {
+        self.min.z == 1
+    }
+
+    fn lower(&self) -> Self {
+        debug_assert!(self.min.z > 1);
+        let down = Pos::new(0, 0, -1);
+        let points = self.points.iter().map(|&p| p + down).collect();
+        let min = self.min + down;
+        Self {
+            id: self.id,
+            points,
+            min,
+        }
+    }
+}
+
+fn fall<'a>(input: impl Iterator<Item = &'a Brick>) -> Vec<Brick> {
+    let mut settled: FxHashSet<Pos> = Default::default();
+    let mut final_bricks = vec![];
+
+    for b in input {
+        let mut current = b.clone();
+        loop {
+            if current.on_ground() {
+                break;
+            }
+            let lower = current.lower();
+            if settled.intersection(&lower.points).next().is_some() {
+                break;
+            }
+            current = lower;
+        }
+        for p in &current.points {
+            settled.insert(*p);
+        }
+        final_bricks.push(current);
+    }
+
+    final_bricks
+}
+
+// Removing brick `id1` will not let any other brick fall lower.
+fn can_remove(id1: usize, final_bricks: &[Brick]) -> bool {
+    for id2 in 0..final_bricks.len() {
+        if id1 == id2 || final_bricks[id2].on_ground() {
+            continue;
+        }
+        if final_bricks[id2].min.z <= final_bricks[id1].min.z {
+            continue;
+        }
+
+        let lower = final_bricks[id2].lower();
+        let can_lower_id2 = final_bricks
+            .iter()
+            .filter(|b| b.id != final_bricks[id1].id && b.id != final_bricks[id2].id)
+            .all(|b| b.points.intersection(&lower.points).next().is_none());
+
+        if can_lower_id2 {
+            return false;
+        }
+    }
+    true
+}
+
+fn how_many_will_fall(
+    id1: usize,
+    final_bricks: &[Brick],
+    final_bricks_map: &FxHashMap<usize, &FxHashSet<Pos>>,
+) -> i16 {
+    let mut ret = 0;
+    let falled_bricks = fall(final_bricks.iter().filter(|b| b.id != final_bricks[id1].id));
+    for b in falled_bricks {
+        if b.points != **final_bricks_map.get(&b.id).unwrap() {
+            ret += 1;
+        }
+    }
+    ret
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<Brick>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut input: Vec<Brick> = parse_lines(input, "{},{},{}~{},{},{}")?
+            .into_iter()
+            .enumerate()
+            .map(|(id, (x1, y1, z1, x2, y2, z2))| {
+                Brick::new(Pos::new(x1, y1, z1), Pos::new(x2, y2, z2), id)
+            })
+            .collect();
+        input.sort_unstable_by_key(|b| b.min.z);
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let bricks = fall(input.iter());
+
+        (0..bricks.len())
+            .into_par_iter()
+            .filter(|id1| can_remove(*id1, &bricks))
+            .count()
+            .into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let bricks = fall(input.iter());
+
+        let mut bricks_map: FxHashMap<usize, &FxHashSet<Pos>> = Default::default();
+        for b in &bricks {
+            bricks_map.insert(b.id, &b.points);
+        }
+
+        (0..bricks.len())
+            .into_par_iter()
+            .map(|id1| how_many_will_fall(id1, &bricks, &bricks_map) as i32)
+            .sum::<i32>()
+            .into()
+    }
+}
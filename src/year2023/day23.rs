@@ -0,0 +1,217 @@
+use anyhow::{ensure, Result};
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::{cmp::Reverse, collections::VecDeque, ops::Add};
+
+use crate::pos::Dir4;
+use crate::solution::{Answer, Solution};
+use crate::{dijkstra::dijkstra, vec::Grid};
+
+type Pos = crate::pos::Pos<i16>;
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash, Default)]
+struct Len(usize);
+
+impl Add for Len {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Len(self.0 + rhs.0)
+    }
+}
+
+impl Ord for Len {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        Reverse(self.0).cmp(&Reverse(other.0))
+    }
+}
+
+impl PartialOrd for Len {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, PartialOrd, Ord, Hash)]
+struct Position {
+    current: Pos,
+    previous: Vec<u16>,
+}
+
+impl Position {
+    fn new(current: Pos) -> Self {
+        Self {
+            current,
+            previous: Default::default(),
+        }
+    }
+
+    fn len(&self, w: i16) -> Len {
+        debug_assert!(
+            !self.previous.contains(&(self.current.idx_1d(w) as u16)),
+            "self: {self:?}"
+        );
+        Len(1)
+    }
+
+    fn neighbours(&self, input: &Grid<u8>) -> Vec<Self> {
+        let w = input.width() as i16;
+
+        if let Ok(dir) = Dir4::try_from(input[self.current] as char) {
+            let p = self.current + dir.delta();
+            if !self.seen(p, w) {
+                debug_assert!(input[p] != b'#');
+                return vec![self.move_to(p, w)];
+            } else {
+                return vec![];
+            }
+        }
+
+        let mut ret = vec![];
+        for next in input.neighbours4(self.current) {
+            if input[next] != b'#' && !self.seen(next, w) {
+                ret.push(self.move_to(next, w));
+            }
+        }
+        ret
+    }
+
+    fn move_to(&self, next: Pos, w: i16) -> Position {
+        debug_assert!(!self.seen(next, w));
+        let mut previous = self.previous.clone();
+        previous.push(self.current.idx_1d(w) as u16);
+        Self {
+            current: next,
+            previous,
+        }
+    }
+
+    fn seen(&self, pos: Pos, w: i16) -> bool {
+        self.previous.contains(&(pos.idx_1d(w) as u16)) || self.current == pos
+    }
+}
+
+fn start_and_end(input: &Grid<u8>) -> (Pos, Pos) {
+    let last = input.height() - 1;
+    let start = input.row(0).iter().position(|c| *c == b'.').unwrap();
+    let end = input.row(last).iter().position(|c| *c == b'.').unwrap();
+    (Pos::new(start as i16, 0), Pos::new(end as i16, last as i16))
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let map = Grid::parse_allowed(input, b".#<>^v")?;
+        ensure!(
+            map.height() > 1
+                && map.row(0).contains(&b'.')
+                && map.row(map.height() - 1).contains(&b'.'),
+            "no path through the first and last row"
+        );
+        Ok(map)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let w = input.width() as i16;
+        let (start, end) = start_and_end(input);
+        let start = Position::new(start);
+
+        let neighbours = |p: &Position| -> Vec<(Position, Len)> {
+            p.neighbours(input)
+                .into_iter()
+                .map(|p| {
+                    let l = p.len(w);
+                    (p, l)
+                })
+                .collect()
+        };
+
+        let (costs, _prevs) = dijkstra(&[start], neighbours);
+        let mut part1 = 0;
+        for (p, c) in costs {
+            if p.current == end {
+                part1 = part1.max(c.0);
+            }
+        }
+        part1.into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let (start, end) = start_and_end(input);
+        let (g, dist) = compress(input, start, end);
+
+        let mut todo: VecDeque<(Pos, Vec<Pos>, usize)> = Default::default();
+        todo.push_back((start, Default::default(), 0));
+
+        let mut part2 = 0;
+        while let Some((next, seen, d)) = todo.pop_front() {
+            debug_assert!(!seen.contains(&next));
+            if next == end {
+                part2 = part2.max(d);
+                continue;
+            }
+            for candidate in g.get(&next).unwrap() {
+                debug_assert_ne!(next, *candidate);
+                if seen.contains(candidate) {
+                    continue;
+                }
+                let mut seen = seen.clone();
+                seen.push(next);
+                let part_d = dist.get(&(next, *candidate)).unwrap();
+                todo.push_back((*candidate, seen, d + part_d));
+            }
+        }
+        part2.into()
+    }
+}
+
+type G = FxHashMap<Pos, FxHashSet<Pos>>;
+
+fn compress(input: &Grid<u8>, start: Pos, end: Pos) -> (G, FxHashMap<(Pos, Pos), usize>) {
+    let mut graph: FxHashMap<Pos, FxHashSet<Pos>> = Default::default();
+    let mut distances: FxHashMap<(Pos, Pos), usize> = Default::default();
+    let mut todo: VecDeque<(Pos, Pos, Pos, usize)> = Default::default();
+    let mut seen: FxHashSet<Pos> = Default::default();
+
+    todo.push_back((start, start, start, 0));
+    seen.insert(start);
+
+    while let Some((orig, prev, next, dist_to_next)) = todo.pop_front() {
+        let dist = dist_to_next + 1;
+        if next == end {
+            graph.entry(orig).or_default().insert(next);
+            graph.entry(next).or_default().insert(orig);
+            distances.insert((orig, next), dist_to_next);
+            distances.insert((next, orig), dist_to_next);
+
+            continue;
+        }
+        let neighbours = neighbours_of(input, next, prev);
+
+        if neighbours.len() == 1 {
+            todo.push_back((orig, next, neighbours[0], dist));
+        } else {
+            graph.entry(orig).or_default().insert(next);
+            graph.entry(next).or_default().insert(orig);
+            distances.insert((orig, next), dist_to_next);
+            distances.insert((next, orig), dist_to_next);
+            if !seen.contains(&next) {
+                seen.insert(next);
+                for n in neighbours {
+                    todo.push_back((next, next, n, 1));
+                }
+            }
+        }
+    }
+
+    (graph, distances)
+}
+
+fn neighbours_of(input: &Grid<u8>, current: Pos, prev: Pos) -> Vec<Pos> {
+    input
+        .neighbours4(current)
+        .filter(|n| *n != prev && input[*n] != b'#')
+        .collect()
+}
@@ -0,0 +1,149 @@
+use anyhow::Result;
+use std::ops::{Add, Mul};
+use z3::ast::Ast;
+use z3::*;
+
+use crate::input::parse_lines;
+use crate::pos::Pos3;
+use crate::solution::{Answer, Params, Solution};
+
+pub type Pos = Pos3<i64>;
+
+type Pos2d = crate::pos::Pos<f64>;
+
+fn to_2d(p: Pos) -> Pos2d {
+    p.map(|v| v as f64).xy()
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+enum Intersection {
+    Point(Pos2d, f64, f64),
+    Parallel,
+    Colinear,
+}
+
+fn lines_intersect((p, r): (Pos2d, Pos2d), (q, s): (Pos2d, Pos2d)) -> Intersection {
+    let eps = 0.001;
+
+    // https://stackoverflow.com/questions/563198/how-do-you-detect-where-two-line-segments-intersect
+    let denominator: f64 = r.cross(s);
+    let numerator: f64 = (q - p).cross(r);
+    if denominator == 0.0 && numerator == 0.0 {
+        Intersection::Colinear
+    } else if denominator.abs() < eps && denominator.abs() > eps {
+        Intersection::Parallel
+    } else {
+        let u = numerator / denominator;
+        let t = (q - p).cross(s) / denominator;
+
+        Intersection::Point(p + r * t, t, u)
+    }
+}
+
+pub struct Hailstones {
+    stones: Vec<(Pos, Pos)>,
+    /// Bounds of both `x` and `y` of the area checked in part 1.
+    test_area: (f64, f64),
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Hailstones;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input> {
+        let stones = parse_lines(input, "{}, {}, {} @ {}, {}, {}")?
+            .into_iter()
+            .map(|(x, y, z, dx, dy, dz)| (Pos::new(x, y, z), Pos::new(dx, dy, dz)))
+            .collect();
+        let test_area = (
+            params.get("min", 200000000000000f64)?,
+            params.get("max", 400000000000000f64)?,
+        );
+        Ok(Hailstones { stones, test_area })
+    }
+
+    fn part1(
+        Hailstones {
+            stones: input,
+            test_area,
+        }: &Self::Input,
+    ) -> Answer {
+        let test_area = *test_area;
+        let test_area_x = test_area;
+        let test_area_y = test_area;
+
+        let mut part1 = 0u64;
+        for i in 0..input.len() {
+            for j in (i + 1)..input.len() {
+                if let Intersection::Point(p, t, u) = lines_intersect(
+                    (to_2d(input[i].0), to_2d(input[i].1)),
+                    (to_2d(input[j].0), to_2d(input[j].1)),
+                ) {
+                    if p.x >= test_area_x.0
+                        && p.x <= test_area_x.1
+                        && p.y >= test_area_y.0
+                        && p.y <= test_area_y.1
+                        && t.is_sign_positive()
+                        && u.is_sign_positive()
+                    {
+                        part1 += 1;
+                    }
+                }
+            }
+        }
+        part1.into()
+    }
+
+    fn part2(Hailstones { stones, .. }: &Self::Input) -> Answer {
+        find_part2_solution(stones).into()
+    }
+}
+
+fn find_part2_solution(input: &[(Pos, Pos)]) -> i64 {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let x = ast::Int::new_const(&ctx, "x");
+    let y = ast::Int::new_const(&ctx, "y");
+    let z = ast::Int::new_const(&ctx, "z");
+
+    let dx = ast::Int::new_const(&ctx, "dx");
+    let dy = ast::Int::new_const(&ctx, "dy");
+    let dz = ast::Int::new_const(&ctx, "dz");
+
+    for (id, (l1, d1)) in input.iter().enumerate() {
+        let lx = ast::Int::from_i64(&ctx, l1.x);
+        let ly = ast::Int::from_i64(&ctx, l1.y);
+        let lz = ast::Int::from_i64(&ctx, l1.z);
+        let ldx = ast::Int::from_i64(&ctx, d1.x);
+        let ldy = ast::Int::from_i64(&ctx, d1.y);
+        let ldz = ast::Int::from_i64(&ctx, d1.z);
+
+        let t = ast::Int::new_const(&ctx, format!("t{id}"));
+
+        let tmp_x = lx.add(ldx.mul(&t));
+        let tmp_xx = (&x).add((&dx).mul(&t));
+        solver.assert(&tmp_x._eq(&tmp_xx));
+
+        let tmp_y = ly.add(ldy.mul(&t));
+        let tmp_yy = (&y).add((&dy).mul(&t));
+        solver.assert(&tmp_y._eq(&tmp_yy));
+
+        let tmp_z = lz.add(ldz.mul(&t));
+        let tmp_zz = (&z).add((&dz).mul(&t));
+        solver.assert(&tmp_z._eq(&tmp_zz));
+    }
+
+    assert_eq!(solver.check(), SatResult::Sat);
+    let model = solver.get_model().unwrap();
+    let x = model.get_const_interp(&x).unwrap().as_i64().unwrap();
+    let y = model.get_const_interp(&y).unwrap().as_i64().unwrap();
+    let z = model.get_const_interp(&z).unwrap().as_i64().unwrap();
+    x + y + z
+}
@@ -0,0 +1,94 @@
+use anyhow::{bail, ensure, Context, Result};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::input::{try_lines, Pattern};
+use crate::solution::{Answer, Params, Solution};
+
+type Graph = FxHashMap<String, FxHashSet<String>>;
+
+/// Wiring diagram and the three wires splitting it into two groups.
+pub struct Wiring {
+    graph: Graph,
+    cut: Vec<(String, String)>,
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Wiring;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input> {
+        let pattern = Pattern::new("{}: {}")?;
+        let is_name = |s: &str| s.len() == 3 && s.bytes().all(|b| b.is_ascii_lowercase());
+        let input = try_lines(input, |line| {
+            let (key, values): (String, String) = pattern.parse(line)?;
+            for name in [key.as_str()].into_iter().chain(values.split_whitespace()) {
+                ensure!(is_name(name), "'{name}' is not three lowercase letters");
+            }
+            Ok((key, values))
+        })?;
+
+        let mut g: Graph = Default::default();
+
+        for (key, values) in &input {
+            for value in values.split_whitespace() {
+                g.entry(key.clone()).or_default().insert(value.to_owned());
+                g.entry(value.to_owned()).or_default().insert(key.clone());
+            }
+        }
+
+        // Found with graphvis in output_full.svg
+        let cut: String = params.get("cut", "njn/xtx,rhh/mtc,tmb/gpj".to_owned())?;
+        let cut = cut
+            .split(',')
+            .map(|wire| {
+                let (a, b) = wire.split_once('/').context("wire is not 'a/b'")?;
+                if !g.get(a).is_some_and(|n| n.contains(b)) {
+                    bail!("wire {wire} is not in the diagram");
+                }
+                Ok((a.to_owned(), b.to_owned()))
+            })
+            .collect::<Result<Vec<_>>>()
+            .with_context(|| format!("cut={cut}"))?;
+        ensure!(
+            cut.len() == 3,
+            "{} wires in the cut instead of 3",
+            cut.len()
+        );
+
+        Ok(Wiring { graph: g, cut })
+    }
+
+    fn part1(Wiring { graph, cut }: &Self::Input) -> Answer {
+        let mut g = graph.clone();
+        for (a, b) in cut {
+            g.get_mut(a).unwrap().remove(b);
+            g.get_mut(b).unwrap().remove(a);
+        }
+        (component(&cut[0].0, &g).len() * component(&cut[0].1, &g).len()).into()
+    }
+
+    fn part2(_: &Self::Input) -> Answer {
+        Answer::None
+    }
+}
+
+fn component(start: &str, g: &Graph) -> FxHashSet<String> {
+    let mut ret: FxHashSet<String> = Default::default();
+    let mut todo: FxHashSet<String> = Default::default();
+    todo.insert(start.to_owned());
+    while !todo.is_empty() {
+        let next = todo.iter().next().unwrap().to_owned();
+        todo.remove(&next);
+        if ret.insert(next.clone()) {
+            for cand in g.get(&next).unwrap() {
+                todo.insert(cand.to_owned());
+            }
+        }
+    }
+    ret
+}
@@ -0,0 +1,35 @@
+//! Counts are process-wide, so the allocator gets a test binary of its own
+//! with a single test, where no other test allocates at the same time.
+#![cfg(feature = "alloc-stats")]
+
+use aoc23::alloc::{measure, AllocStats, CountingAllocator};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn counts_allocations_of_each_run() {
+    let (first, stats) = measure(|| Vec::<u8>::with_capacity(1000));
+    assert_eq!(
+        stats,
+        AllocStats {
+            allocations: 1,
+            bytes: 1000,
+            peak: 1000
+        }
+    );
+
+    // The buffer of the first run is still live, but not counted again.
+    let (second, stats) = measure(|| Vec::<u8>::with_capacity(10));
+    assert_eq!(
+        stats,
+        AllocStats {
+            allocations: 1,
+            bytes: 10,
+            peak: 10
+        }
+    );
+
+    let ((), stats) = measure(|| drop((first, second)));
+    assert_eq!(stats, AllocStats::default());
+}
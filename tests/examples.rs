@@ -0,0 +1,145 @@
+use anyhow::{Context, Result};
+use aoc23::answers::{Answers, Check};
+use aoc23::solution::Answer;
+use aoc23::{Year, YEARS};
+use std::collections::BTreeSet;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+
+/// Finds example inputs, named `dayNN_test*`, and returns them with their day.
+fn examples(inputs: &Path) -> Result<Vec<(usize, String)>> {
+    let mut examples = vec![];
+    for entry in std::fs::read_dir(inputs)? {
+        let name = entry?.file_name().to_string_lossy().into_owned();
+        let Some(day) = name
+            .strip_prefix("day")
+            .filter(|rest| rest.get(2..).is_some_and(|s| s.starts_with("_test")))
+            .map(|rest| &rest[..2])
+        else {
+            continue;
+        };
+        examples.push((day.parse()?, name));
+    }
+    examples.sort();
+    Ok(examples)
+}
+
+fn run_example(
+    year: &Year,
+    inputs: &Path,
+    answers: &Answers,
+    day: usize,
+    name: &str,
+) -> Result<Check> {
+    let solution = year.days[day - 1];
+    let input = std::fs::read_to_string(inputs.join(name))?;
+    let parsed = solution.parse(&input, &answers.params(name))?;
+    // Parts without an expected answer may not even terminate on an example.
+    let part1 = if answers.is_checked(name, 1) {
+        solution.part1(&parsed)?
+    } else {
+        Answer::None
+    };
+    let part2 = if answers.is_checked(name, 2) {
+        solution.part2(&parsed)?
+    } else {
+        Answer::None
+    };
+    Ok(answers.check(name, &[part1, part2]))
+}
+
+#[test]
+fn examples_give_expected_answers() -> Result<()> {
+    let mut failures = vec![];
+    for year in YEARS {
+        let inputs = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("inputs")
+            .join(year.year.to_string());
+        let answers = Answers::load(&inputs.join("answers"))?;
+        let examples = examples(&inputs)?;
+        assert!(!examples.is_empty(), "no examples in {}", inputs.display());
+
+        for (day, name) in examples {
+            let check = run_example(year, &inputs, &answers, day, &name)
+                .with_context(|| format!("{}/{name}", year.year))?;
+            let name = format!("{}/{name}", year.year);
+            match check {
+                Check::Ok => {}
+                Check::Missing => failures.push(format!("{name}: no expected answers")),
+                Check::Failed(mismatches) => {
+                    for mismatch in mismatches {
+                        failures.push(format!("{name}:\n{mismatch}"));
+                    }
+                }
+            }
+        }
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+    Ok(())
+}
+
+/// Finds inputs of all days, named `dayNN` followed by anything, like the
+/// examples, and returns them with their day.
+fn inputs(inputs: &Path) -> Result<Vec<(usize, String)>> {
+    let mut found = vec![];
+    for entry in std::fs::read_dir(inputs)? {
+        let name = entry?.file_name().to_string_lossy().into_owned();
+        if let Some(day) = name.strip_prefix("day").and_then(|rest| rest.get(..2)) {
+            found.push((day.parse()?, name));
+        }
+    }
+    found.sort();
+    Ok(found)
+}
+
+#[test]
+fn truncated_inputs_fail_to_parse_or_still_solve() -> Result<()> {
+    let year = YEARS.iter().find(|y| y.year == 2023).unwrap();
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs/2023");
+    let answers = Answers::load(&dir.join("answers"))?;
+    let mut days = BTreeSet::new();
+    let mut failures = vec![];
+    for (day, name) in inputs(&dir)? {
+        days.insert(day);
+        let solution = year.days[day - 1];
+        let input = std::fs::read_to_string(dir.join(&name))?;
+        let trimmed = input.trim_end();
+        let last_line = trimmed.rfind('\n').map_or(0, |i| i + 1);
+        let middle_of_last_line = last_line + (trimmed.len() - last_line) / 2;
+        let mut two_thirds = input.len() * 2 / 3;
+        while !input.is_char_boundary(two_thirds) {
+            two_thirds -= 1;
+        }
+
+        // Errors of a line cut in the middle must say where it is, while a
+        // cut elsewhere may leave a file without one of its sections.
+        for (end, locate) in [(middle_of_last_line, true), (two_thirds, false)] {
+            let cut = &input[..end];
+            let outcome = panic::catch_unwind(AssertUnwindSafe(|| -> Result<()> {
+                let parsed = solution.parse(cut, &answers.params(&name))?;
+                // Parts without an expected answer may not terminate, as in
+                // `run_example`.
+                for part in [1, 2].into_iter().filter(|p| answers.is_checked(&name, *p)) {
+                    match part {
+                        1 => solution.part1(&parsed)?,
+                        _ => solution.part2(&parsed)?,
+                    };
+                }
+                Ok(())
+            }));
+            match outcome {
+                Ok(Ok(())) => {}
+                Ok(Err(e)) => {
+                    let e = format!("{e:#}");
+                    if locate && !e.contains("line") && !e.contains("column") {
+                        failures.push(format!("{name} cut at {end}: no position in '{e}'"));
+                    }
+                }
+                Err(_) => failures.push(format!("{name} cut at {end}: panicked")),
+            }
+        }
+    }
+    assert_eq!((1..=25).collect::<BTreeSet<_>>(), days);
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+    Ok(())
+}